@@ -0,0 +1,37 @@
+use anyhow::Result;
+use matplotlib_pyo3::{
+    BandOptions, FillBetweenOptions, FillOptions, LegendOptions, LineOptions, PyPlot,
+};
+use ndarray::Array2;
+
+fn main() -> Result<()> {
+    let x: Vec<f64> = (0..50).map(|i| i as f64 * 0.2).collect();
+    // Deterministic stand-in for Monte Carlo draws: 200 rows, one column per point of x.
+    let samples = Array2::from_shape_fn((200, x.len()), |(draw, i)| {
+        let noise = ((draw * 7919 + i * 104729) % 1000) as f64 / 1000. - 0.5;
+        x[i].sin() + noise * (0.2 + 0.1 * x[i])
+    });
+    PyPlot::with_plt(|plt| {
+        let fig = plt.figure()?;
+        let ax = fig.gca()?;
+        ax.quantile_bands(
+            x.iter().copied(),
+            samples.view(),
+            &BandOptions::new().median(LineOptions::new().label("median")),
+        )?;
+        let upper: Vec<f64> = x.iter().map(|x| x.sin() + 1.).collect();
+        let above: Vec<bool> = upper.iter().map(|y| *y > 1.5).collect();
+        ax.fill_between(
+            x.iter().copied(),
+            upper,
+            vec![1.5; x.len()],
+            &FillBetweenOptions::new()
+                .mask(&above)
+                .interpolate(true)
+                .fill(FillOptions::new().hatch("//").alpha(0.3).label("above 1.5")),
+        )?;
+        ax.legend(&LegendOptions::new())?;
+        plt.show()?;
+        Ok(())
+    })
+}
@@ -8,7 +8,7 @@ fn main() -> Result<()> {
     PyPlot::with_plt(|plt| {
         let fig = plt.figure()?;
         let ax = fig.gca()?;
-        ax.bar(x, y, Some(widths), false)?;
+        ax.bar(x, y, Some(widths), false, None)?;
         plt.show()?;
         Ok(())
     })
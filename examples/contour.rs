@@ -0,0 +1,35 @@
+use anyhow::Result;
+use matplotlib_pyo3::*;
+use ndarray::{Array1, Array2};
+
+fn main() -> Result<()> {
+    let x: Array1<f64> = Array1::linspace(-2., 2., 60);
+    let y: Array1<f64> = Array1::linspace(-1., 1., 40);
+    let z = Array2::from_shape_fn((y.len(), x.len()), |(i, j)| {
+        (-(x[j] * x[j] + 4. * y[i] * y[i])).exp() * (3. * x[j]).cos()
+    });
+    let coords = GridCoordinates::Vectors {
+        x: x.view(),
+        y: y.view(),
+    };
+    PyPlot::with_plt(|plt| {
+        let fig = plt.figure()?;
+        let ax = fig.gca()?;
+        let filled = ax.contourf(
+            z.view(),
+            Some(coords),
+            &ContourOptions::new().levels(12).cmap("RdBu_r"),
+        )?;
+        let lines = ax.contour(
+            z.view(),
+            Some(coords),
+            &ContourOptions::new()
+                .levels(vec![-0.5, 0., 0.5])
+                .colors(&["k".parse()?]),
+        )?;
+        lines.clabel(&ClabelOptions::new().inline(true).format("%.1f"))?;
+        fig.colorbar(&filled, &ax, &ColorbarOptions::new().label("z"))?;
+        plt.show()?;
+        Ok(())
+    })
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+use matplotlib_pyo3::*;
+
+fn main() -> Result<()> {
+    // Deterministic stand-in for measured samples of three groups.
+    let groups: Vec<Vec<f64>> = (1..=3)
+        .map(|g| {
+            (0..200)
+                .map(|i| {
+                    let u = ((i * 7919 + g * 104729) % 1000) as f64 / 1000.;
+                    g as f64 + (u - 0.5).powi(3) * 8. * g as f64
+                })
+                .collect()
+        })
+        .collect();
+    let labels = ["low", "mid", "high"];
+    PyPlot::with_plt(|plt| {
+        let (_fig, axes) = plt.subplots(1, 2, ShareAxes::None, ShareAxes::All)?;
+        let stats = axes[[0, 0]].boxplot(
+            &groups,
+            &BoxplotOptions::new()
+                .labels(&labels)
+                .notch(true)
+                .whiskers(Whiskers::Percentiles(5., 95.))
+                .colors(&["c0".parse()?, "c1".parse()?, "c2".parse()?]),
+        )?;
+        axes[[0, 1]].violinplot(
+            &groups,
+            &ViolinOptions::new()
+                .labels(&labels)
+                .show_medians(true)
+                .quantiles(&[0.25, 0.75]),
+        )?;
+        println!("group   median       q1       q3  fliers");
+        for (label, stats) in labels.iter().zip(&stats) {
+            println!(
+                "{:<5} {:>8.3} {:>8.3} {:>8.3} {:>7}",
+                label,
+                stats.median,
+                stats.q1,
+                stats.q3,
+                stats.fliers.len()
+            );
+        }
+        plt.show()?;
+        Ok(())
+    })
+}
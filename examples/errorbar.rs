@@ -0,0 +1,32 @@
+use anyhow::Result;
+use matplotlib_pyo3::{
+    ErrorValues, ErrorbarOptions, LegendOptions, LineOptions, LineStyle, Marker, PyPlot,
+};
+
+fn main() -> Result<()> {
+    let x: Vec<f64> = (0..10).map(|i| i as f64).collect();
+    let y: Vec<f64> = x.iter().map(|x| (x * 0.5).sin()).collect();
+    let lower: Vec<f64> = x.iter().map(|x| 0.05 + 0.01 * x).collect();
+    let upper: Vec<f64> = x.iter().map(|x| 0.1 + 0.02 * x).collect();
+    PyPlot::with_plt(|plt| {
+        let fig = plt.figure()?;
+        let ax = fig.gca()?;
+        ax.errorbar(
+            x.iter().copied(),
+            y.iter().copied(),
+            Some(ErrorValues::Asymmetric { lower, upper }),
+            Some(0.2.into()),
+            &ErrorbarOptions::new()
+                .line(
+                    LineOptions::new()
+                        .style(LineStyle::Dashed)
+                        .marker(Marker::Circle)
+                        .label("sin(x / 2)"),
+                )
+                .cap_size(3.),
+        )?;
+        ax.legend(&LegendOptions::new())?;
+        plt.show()?;
+        Ok(())
+    })
+}
@@ -0,0 +1,20 @@
+use anyhow::Result;
+use matplotlib_pyo3::{LineOptions, PyPlot};
+
+fn main() -> Result<()> {
+    let seconds: Vec<f64> = (0..10).map(|i| i as f64 * 450.).collect();
+    let costs: Vec<f64> = seconds.iter().map(|s| 0.002 * s).collect();
+    PyPlot::with_plt(|plt| {
+        let fig = plt.figure()?;
+        let ax = fig.gca()?;
+        ax.line(seconds, costs, &LineOptions::new())?;
+        ax.xaxis()?.set_formatter(|value, _| {
+            let minutes = (value / 60.).round() as i64;
+            format!("{}h{:02}", minutes / 60, minutes % 60)
+        })?;
+        ax.yaxis()?
+            .set_formatter(|value, _| format!("${:.2}", value))?;
+        plt.show()?;
+        Ok(())
+    })
+}
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use matplotlib_pyo3::{GridSpecOptions, PyPlot};
+
+fn main() -> Result<()> {
+    let data: Vec<f64> = (0..200).map(|i| ((i * 37) % 101) as f64).collect();
+    PyPlot::with_plt(|plt| {
+        let fig = plt.figure()?;
+        let gs = fig.add_gridspec(
+            3,
+            3,
+            &GridSpecOptions::new()
+                .width_ratios(&[2., 1., 1.])
+                .hspace(0.4),
+        )?;
+        let main = fig.add_subplot(&gs.slice(0..2, ..)?)?;
+        main.hist(data.clone(), Some(20), None)?;
+        main.set_title("spans two rows")?;
+        fig.add_subplot(&gs.slice(2, 0)?)?
+            .set_title("bottom left")?;
+        fig.add_subplot(&gs.slice(2, 1..=2)?)?
+            .set_title("spans two columns")?;
+        plt.show()?;
+        Ok(())
+    })
+}
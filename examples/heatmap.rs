@@ -14,7 +14,8 @@ fn main() -> Result<()> {
         let plt = PyPlot::new(py)?;
         let fig = plt.figure()?;
         let ax = fig.gca()?;
-        ax.heatmap(data.view())?;
+        let image = ax.heatmap(data.view(), None)?;
+        fig.colorbar(&image, &ax, &ColorbarOptions::new().label("value"))?;
         plt.show()?;
         Ok(())
     })
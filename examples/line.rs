@@ -0,0 +1,57 @@
+use anyhow::Result;
+use matplotlib_pyo3::{
+    AnnotateOptions, ArrowOptions, ArrowStyle, BoxOptions, BoxStyle, Color, Coordinates,
+    LegendLocation, LegendOptions, LineOptions, LineStyle, Marker, PyPlot, TextOptions,
+};
+
+fn main() -> Result<()> {
+    let x: Vec<f64> = (0..50).map(|i| i as f64 / 5.).collect();
+    let y: Vec<f64> = x.iter().map(|x| x.sin()).collect();
+    PyPlot::with_plt(|plt| {
+        let fig = plt.figure()?;
+        let ax = fig.gca()?;
+        ax.line(
+            x.clone(),
+            y,
+            &LineOptions::new()
+                .color("tab:red".parse::<Color>()?)
+                .width(2.)
+                .style(LineStyle::Dashed)
+                .marker(Marker::Circle)
+                .marker_size(4.)
+                .label("sin"),
+        )?;
+        ax.line(
+            x.clone(),
+            x.iter().map(|x| x.cos()),
+            &LineOptions::new()
+                .color(Color::from_colormap("viridis", 0.7))
+                .dashes(0., &[4., 2., 1., 2.])
+                .alpha(0.5)
+                .label("cos"),
+        )?;
+        ax.annotate(
+            "peak",
+            (std::f64::consts::FRAC_PI_2, 1.),
+            Some((20., -30.)),
+            &AnnotateOptions::new()
+                .text_coords(Coordinates::OffsetPoints)
+                .arrow(ArrowOptions::new(ArrowStyle::Head).curvature(0.2)),
+        )?;
+        ax.text(
+            0.02,
+            0.02,
+            "y = sin(x)",
+            Coordinates::AxesFraction,
+            &TextOptions::new().bbox(BoxOptions::new(BoxStyle::Round).pad(0.3)),
+        )?;
+        ax.legend(
+            &LegendOptions::new()
+                .location(LegendLocation::UpperLeft)
+                .anchor(1.02, 1.)
+                .frame(false),
+        )?;
+        plt.show()?;
+        Ok(())
+    })
+}
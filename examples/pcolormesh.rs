@@ -0,0 +1,33 @@
+use anyhow::Result;
+use matplotlib_pyo3::*;
+use ndarray::{Array1, Array2};
+
+fn main() -> Result<()> {
+    // Log-spaced frequency bin edges and linear time bin edges.
+    let freq_edges = Array1::from_shape_fn(33, |i| 10f64.powf(1. + 3. * i as f64 / 32.));
+    let time_edges: Array1<f64> = Array1::linspace(0., 10., 51);
+    let power = Array2::from_shape_fn((32, 50), |(f, t)| {
+        let freq = (freq_edges[f] * freq_edges[f + 1]).sqrt();
+        let time = 0.5 * (time_edges[t] + time_edges[t + 1]);
+        1. + (freq.ln() - 5. - 0.2 * time).powi(2).recip()
+    });
+    PyPlot::with_plt(|plt| {
+        let fig = plt.figure()?;
+        let ax = fig.gca()?;
+        let mesh = ax.pcolormesh(
+            power.view(),
+            Some(GridCoordinates::Vectors {
+                x: time_edges.view(),
+                y: freq_edges.view(),
+            }),
+            &MeshOptions::new()
+                .shading(Shading::Flat)
+                .cmap("magma")
+                .norm(Norm::Log),
+        )?;
+        ax.set_yscale(Scale::Log { base: 10. })?;
+        fig.colorbar(&mesh, &ax, &ColorbarOptions::new().label("power"))?;
+        plt.show()?;
+        Ok(())
+    })
+}
@@ -0,0 +1,32 @@
+use anyhow::Result;
+use matplotlib_pyo3::*;
+
+fn main() -> Result<()> {
+    let values = [42., 27., 18., 13.];
+    let labels = ["compute", "storage", "network", "other"];
+    let total: f64 = values.iter().sum();
+    PyPlot::with_plt(|plt| {
+        let (_fig, axes) = plt.subplots(1, 2, ShareAxes::None, ShareAxes::None)?;
+        axes[[0, 0]].pie(
+            &values,
+            Some(&labels),
+            &PieOptions::new()
+                .autopct("%.1f%%")
+                .explode(&[0.1, 0., 0., 0.])
+                .start_angle(90.)
+                .counterclock(false),
+        )?;
+        let donut = axes[[0, 1]].pie(
+            &values,
+            Some(&labels),
+            &PieOptions::new()
+                .autopct_with(move |pct| format!("{:.0} k$", pct / 100. * total))
+                .wedge_width(0.4)
+                .pct_distance(0.8)
+                .edge_color("white".parse::<Color>()?),
+        )?;
+        donut.wedges[3].set_face_color("lightgray".parse::<Color>()?)?;
+        plt.show()?;
+        Ok(())
+    })
+}
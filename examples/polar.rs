@@ -0,0 +1,50 @@
+use anyhow::Result;
+use matplotlib_pyo3::*;
+use std::f64::consts::PI;
+
+fn main() -> Result<()> {
+    let sectors = 16;
+    let width = 2. * PI / sectors as f64;
+    let directions: Vec<f64> = (0..sectors).map(|i| i as f64 * width).collect();
+    let speeds: Vec<f64> = directions
+        .iter()
+        .map(|d| 3. + 2. * (d - PI / 4.).cos())
+        .collect();
+    let theta: Vec<f64> = (0..=360).map(|i| (i as f64).to_radians()).collect();
+    let gain: Vec<f64> = theta.iter().map(|t| (2. * t).cos().abs() + 0.1).collect();
+    PyPlot::with_plt(|plt| {
+        let fig = plt.figure()?;
+        let grid = fig.add_gridspec(1, 2, &GridSpecOptions::new())?;
+
+        let rose = fig
+            .add_subplot_with(&grid.slice(0, 0)?, Projection::Polar)?
+            .as_polar()?;
+        rose.set_theta_zero_location(CompassDirection::N, 0.)?
+            .set_theta_direction(ThetaDirection::Clockwise)?
+            .bar(
+                directions,
+                speeds,
+                Some(width),
+                &FillOptions::new()
+                    .edge_color("white".parse::<Color>()?)
+                    .alpha(0.8),
+            )?;
+        rose.as_axes().set_title("wind rose")?;
+
+        let pattern = fig
+            .add_subplot_with(&grid.slice(0, 1)?, Projection::Polar)?
+            .as_polar()?;
+        pattern
+            .fill(
+                theta.iter().copied(),
+                gain.iter().copied(),
+                &FillOptions::new().alpha(0.3),
+            )?
+            .line(theta, gain, &LineOptions::new())?
+            .set_rlim(Some(0.), Some(1.2))?
+            .set_rticks(&[0.5, 1.])?;
+        pattern.as_axes().set_title("antenna pattern")?;
+        plt.show()?;
+        Ok(())
+    })
+}
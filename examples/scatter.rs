@@ -0,0 +1,26 @@
+use anyhow::Result;
+use matplotlib_pyo3::{Color, PyPlot, ScatterOptions};
+
+fn main() -> Result<()> {
+    let x: Vec<f64> = (0..30).map(|i| (i as f64 * 0.7).sin()).collect();
+    let y: Vec<f64> = (0..30).map(|i| (i as f64 * 1.3).cos()).collect();
+    let sizes: Vec<f64> = (0..30).map(|i| 20. + 10. * i as f64).collect();
+    let values: Vec<f64> = (0..30).map(|i| i as f64).collect();
+    PyPlot::with_plt(|plt| {
+        let fig = plt.figure()?;
+        let ax = fig.gca()?;
+        ax.scatter_with(
+            x,
+            y,
+            &ScatterOptions::new()
+                .sizes(&sizes)
+                .values(&values)
+                .cmap("viridis")
+                .range(Some(5.), None)
+                .edge_color("k".parse::<Color>()?)
+                .alpha(0.7),
+        )?;
+        plt.show()?;
+        Ok(())
+    })
+}
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use matplotlib_pyo3::{LineOptions, PyPlot};
+
+fn main() -> Result<()> {
+    let x: Vec<f64> = (0..100).map(|i| i as f64 / 10.).collect();
+    PyPlot::with_plt(|plt| {
+        plt.rc_params()?.set_font_size(12.)?.set_line_width(2.)?;
+        plt.with_rc(&[("axes.grid", &true), ("grid.alpha", &0.3)], |plt| {
+            plt.with_style(&["ggplot"], |plt| {
+                let fig = plt.figure()?;
+                let ax = fig.gca()?;
+                for k in 1..4 {
+                    let k = k as f64;
+                    ax.line(
+                        x.clone(),
+                        x.iter().map(|x| (k * x).sin() / k),
+                        &LineOptions::new(),
+                    )?;
+                }
+                plt.show()?;
+                Ok::<_, anyhow::Error>(())
+            })
+        })
+    })
+}
@@ -0,0 +1,20 @@
+use anyhow::Result;
+use matplotlib_pyo3::{LineOptions, PyPlot, ShareAxes};
+
+fn main() -> Result<()> {
+    let x: Vec<f64> = (0..100).map(|i| i as f64 / 10.).collect();
+    PyPlot::with_plt(|plt| {
+        let (_fig, axes) = plt.subplots(2, 2, ShareAxes::All, ShareAxes::Row)?;
+        for ((row, col), ax) in axes.indexed_iter() {
+            let k = (2 * row + col + 1) as f64;
+            ax.line(
+                x.clone(),
+                x.iter().map(|x| (k * x).sin()),
+                &LineOptions::new(),
+            )?;
+            ax.set_title(&format!("sin({}x)", k))?;
+        }
+        plt.show()?;
+        Ok(())
+    })
+}
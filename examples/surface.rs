@@ -0,0 +1,45 @@
+use anyhow::Result;
+use matplotlib_pyo3::*;
+use ndarray::Array2;
+
+fn main() -> Result<()> {
+    let n = 40;
+    let x = Array2::from_shape_fn((n, n), |(_, j)| -2. + 4. * j as f64 / (n - 1) as f64);
+    let y = Array2::from_shape_fn((n, n), |(i, _)| -2. + 4. * i as f64 / (n - 1) as f64);
+    // Rosenbrock-like optimization landscape.
+    let z = Array2::from_shape_fn((n, n), |(i, j)| {
+        let (x, y) = (x[[i, j]], y[[i, j]]);
+        ((1. - x).powi(2) + 10. * (y - x * x).powi(2)).ln_1p()
+    });
+    PyPlot::with_plt(|plt| {
+        let fig = plt.figure()?;
+        let ax = fig.add_axes3d()?;
+        let surface = ax.plot_surface(
+            x.view(),
+            y.view(),
+            z.view(),
+            &SurfaceOptions::new().cmap("viridis").alpha(0.9),
+        )?;
+        ax.plot_wireframe(
+            x.view(),
+            y.view(),
+            z.view(),
+            &WireframeOptions::new()
+                .color("k".parse::<Color>()?)
+                .line_width(0.3)
+                .stride(4, 4),
+        )?;
+        ax.scatter3d(
+            [1.],
+            [1.],
+            [0.],
+            &ScatterOptions::new().color("red".parse::<Color>()?),
+        )?;
+        ax.set_zlabel("log(1 + f)")?;
+        ax.view_init(35., -120.)?;
+        ax.as_axes().set_title("landscape")?;
+        fig.colorbar(&surface, &ax.as_axes(), &ColorbarOptions::new().shrink(0.6))?;
+        plt.show()?;
+        Ok(())
+    })
+}
@@ -0,0 +1,356 @@
+use crate::{
+    check_alpha, Axes, Color, Error, FontWeight, HorizontalAlignment, Result, Text,
+    VerticalAlignment,
+};
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+str_enum! {
+    /// Coordinate systems of text positions and annotated points.
+    pub enum Coordinates: "coordinate system" {
+        /// The data coordinates of the axes.
+        Data => "data",
+        /// `(0, 0)` is the lower left and `(1, 1)` the upper right corner of the axes.
+        AxesFraction => "axes fraction",
+        /// `(0, 0)` is the lower left and `(1, 1)` the upper right corner of the figure.
+        FigureFraction => "figure fraction",
+        /// Offset in points from the annotated point, only valid for annotation text.
+        OffsetPoints => "offset points",
+        /// Offset in pixels from the annotated point, only valid for annotation text.
+        OffsetPixels => "offset pixels",
+    }
+}
+
+str_enum! {
+    /// Shapes of the box drawn around text, see `matplotlib.patches.BoxStyle`.
+    pub enum BoxStyle: "box style" {
+        Square => "square",
+        Round => "round",
+        Round4 => "round4",
+        Circle => "circle",
+        Sawtooth => "sawtooth",
+        Roundtooth => "roundtooth",
+        LArrow => "larrow",
+        RArrow => "rarrow",
+        DArrow => "darrow",
+    }
+}
+
+str_enum! {
+    /// Arrow heads and tails, see `matplotlib.patches.ArrowStyle`.
+    pub enum ArrowStyle: "arrow style" {
+        Line => "-",
+        Head => "->",
+        Tail => "<-",
+        Both => "<->",
+        FilledHead => "-|>",
+        FilledTail => "<|-",
+        FilledBoth => "<|-|>",
+        Bracket => "-[",
+        Fancy => "fancy",
+        Simple => "simple",
+        Wedge => "wedge",
+    }
+}
+
+/// Box drawn behind text, see [TextOptions::bbox].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxOptions {
+    style: BoxStyle,
+    pad: Option<f64>,
+    face_color: Option<Color>,
+    edge_color: Option<Color>,
+    alpha: Option<f64>,
+}
+
+impl BoxOptions {
+    pub fn new(style: BoxStyle) -> Self {
+        Self {
+            style,
+            pad: None,
+            face_color: None,
+            edge_color: None,
+            alpha: None,
+        }
+    }
+
+    /// Padding between text and box in fractions of the font size.
+    pub fn pad(mut self, pad: f64) -> Self {
+        self.pad = Some(pad);
+        self
+    }
+
+    pub fn face_color(mut self, color: impl Into<Color>) -> Self {
+        self.face_color = Some(color.into());
+        self
+    }
+
+    pub fn edge_color(mut self, color: impl Into<Color>) -> Self {
+        self.edge_color = Some(color.into());
+        self
+    }
+
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> Result<&'py PyDict> {
+        let bbox = PyDict::new(py);
+        match self.pad {
+            Some(pad) => bbox.set_item("boxstyle", format!("{},pad={}", self.style, pad))?,
+            None => bbox.set_item("boxstyle", self.style)?,
+        }
+        if let Some(color) = &self.face_color {
+            bbox.set_item("facecolor", color.to_py(py)?)?;
+        }
+        if let Some(color) = &self.edge_color {
+            bbox.set_item("edgecolor", color.to_py(py)?)?;
+        }
+        if let Some(alpha) = self.alpha {
+            check_alpha(alpha)?;
+            bbox.set_item("alpha", alpha)?;
+        }
+        Ok(bbox)
+    }
+}
+
+/// Arrow from annotation text to the annotated point, see [AnnotateOptions::arrow].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArrowOptions {
+    style: ArrowStyle,
+    color: Option<Color>,
+    width: Option<f64>,
+    curvature: Option<f64>,
+}
+
+impl ArrowOptions {
+    pub fn new(style: ArrowStyle) -> Self {
+        Self {
+            style,
+            color: None,
+            width: None,
+            curvature: None,
+        }
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Line width in points.
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Bend the arrow into an arc, the sign selects the direction.
+    /// `0` is a straight line, `1` roughly a half circle.
+    pub fn curvature(mut self, rad: f64) -> Self {
+        self.curvature = Some(rad);
+        self
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> Result<&'py PyDict> {
+        let arrow = PyDict::new(py);
+        arrow.set_item("arrowstyle", self.style)?;
+        if let Some(color) = &self.color {
+            arrow.set_item("color", color.to_py(py)?)?;
+        }
+        if let Some(width) = self.width {
+            arrow.set_item("linewidth", width)?;
+        }
+        if let Some(rad) = self.curvature {
+            arrow.set_item("connectionstyle", format!("arc3,rad={}", rad))?;
+        }
+        Ok(arrow)
+    }
+}
+
+/// Styling of text drawn by [Axes::text] and [Axes::annotate].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextOptions {
+    font_size: Option<f64>,
+    font_weight: Option<FontWeight>,
+    color: Option<Color>,
+    horizontal_alignment: Option<HorizontalAlignment>,
+    vertical_alignment: Option<VerticalAlignment>,
+    rotation: Option<f64>,
+    bbox: Option<BoxOptions>,
+}
+
+impl TextOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Font size in points.
+    pub fn font_size(mut self, size: f64) -> Self {
+        self.font_size = Some(size);
+        self
+    }
+
+    pub fn font_weight(mut self, weight: FontWeight) -> Self {
+        self.font_weight = Some(weight);
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Which side of the text is placed at the given position horizontally.
+    pub fn horizontal_alignment(mut self, align: HorizontalAlignment) -> Self {
+        self.horizontal_alignment = Some(align);
+        self
+    }
+
+    /// Which side of the text is placed at the given position vertically.
+    pub fn vertical_alignment(mut self, align: VerticalAlignment) -> Self {
+        self.vertical_alignment = Some(align);
+        self
+    }
+
+    /// Rotation in degrees, counter-clockwise.
+    pub fn rotation(mut self, degrees: f64) -> Self {
+        self.rotation = Some(degrees);
+        self
+    }
+
+    /// Draw a box behind the text.
+    pub fn bbox(mut self, bbox: BoxOptions) -> Self {
+        self.bbox = Some(bbox);
+        self
+    }
+
+    fn kwargs<'py>(&self, py: Python<'py>) -> Result<&'py PyDict> {
+        let kwargs = PyDict::new(py);
+        if let Some(size) = self.font_size {
+            kwargs.set_item("fontsize", size)?;
+        }
+        if let Some(weight) = self.font_weight {
+            kwargs.set_item("fontweight", weight)?;
+        }
+        if let Some(color) = &self.color {
+            kwargs.set_item("color", color.to_py(py)?)?;
+        }
+        if let Some(align) = self.horizontal_alignment {
+            kwargs.set_item("horizontalalignment", align)?;
+        }
+        if let Some(align) = self.vertical_alignment {
+            kwargs.set_item("verticalalignment", align)?;
+        }
+        if let Some(degrees) = self.rotation {
+            kwargs.set_item("rotation", degrees)?;
+        }
+        if let Some(bbox) = &self.bbox {
+            kwargs.set_item("bbox", bbox.to_dict(py)?)?;
+        }
+        Ok(kwargs)
+    }
+}
+
+/// Options of [Axes::annotate].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AnnotateOptions {
+    xy_coords: Option<Coordinates>,
+    text_coords: Option<Coordinates>,
+    arrow: Option<ArrowOptions>,
+    text: TextOptions,
+}
+
+impl AnnotateOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Coordinate system of the annotated point, [Coordinates::Data] by default.
+    pub fn xy_coords(mut self, coords: Coordinates) -> Self {
+        self.xy_coords = Some(coords);
+        self
+    }
+
+    /// Coordinate system of the text position, the one of the annotated point by default.
+    pub fn text_coords(mut self, coords: Coordinates) -> Self {
+        self.text_coords = Some(coords);
+        self
+    }
+
+    /// Draw an arrow from the text to the annotated point.
+    pub fn arrow(mut self, arrow: ArrowOptions) -> Self {
+        self.arrow = Some(arrow);
+        self
+    }
+
+    pub fn text(mut self, text: TextOptions) -> Self {
+        self.text = text;
+        self
+    }
+}
+
+impl<'a> Axes<'a> {
+    /// Add the text `s` at `(x, y)` in the given coordinate system.
+    /// See `matplotlib.axes.Axes.text` for more details.
+    pub fn text(
+        &self,
+        x: f64,
+        y: f64,
+        s: &str,
+        coords: Coordinates,
+        options: &TextOptions,
+    ) -> Result<Text<'a>> {
+        let transform = match coords {
+            Coordinates::Data => self.axes.getattr("transData")?,
+            Coordinates::AxesFraction => self.axes.getattr("transAxes")?,
+            Coordinates::FigureFraction => self.axes.getattr("figure")?.getattr("transFigure")?,
+            Coordinates::OffsetPoints | Coordinates::OffsetPixels => {
+                return Err(Error::invalid_argument(format!(
+                    "invalid coordinate system {:?} for text: offsets need an annotated point",
+                    coords.as_str()
+                )))
+            }
+        };
+        let kwargs = options.kwargs(self.py)?;
+        kwargs.set_item("transform", transform)?;
+        let text = self.axes.call_method("text", (x, y, s), Some(kwargs))?;
+        Ok(Text { text })
+    }
+
+    /// Annotate the point `xy` with `text`, placed at `xytext` or at `xy` itself.
+    /// See `matplotlib.axes.Axes.annotate` for more details.
+    pub fn annotate(
+        &self,
+        text: &str,
+        xy: (f64, f64),
+        xytext: Option<(f64, f64)>,
+        options: &AnnotateOptions,
+    ) -> Result<Text<'a>> {
+        if let Some(coords @ (Coordinates::OffsetPoints | Coordinates::OffsetPixels)) =
+            options.xy_coords
+        {
+            return Err(Error::invalid_argument(format!(
+                "invalid coordinate system {:?} for the annotated point",
+                coords.as_str()
+            )));
+        }
+        let kwargs = options.text.kwargs(self.py)?;
+        if let Some(xytext) = xytext {
+            kwargs.set_item("xytext", xytext)?;
+        }
+        if let Some(coords) = options.xy_coords {
+            kwargs.set_item("xycoords", coords)?;
+        }
+        if let Some(coords) = options.text_coords {
+            kwargs.set_item("textcoords", coords)?;
+        }
+        if let Some(arrow) = &options.arrow {
+            kwargs.set_item("arrowprops", arrow.to_dict(self.py)?)?;
+        }
+        let text = self
+            .axes
+            .call_method("annotate", (text, xy), Some(kwargs))?;
+        Ok(Text { text })
+    }
+}
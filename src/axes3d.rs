@@ -0,0 +1,418 @@
+use crate::colorbar::private::Sealed;
+use crate::{
+    check_alpha, Axes, Color, Error, Figure, LineOptions, Projection, Result, ScalarMappable,
+    ScatterOptions, Text,
+};
+use ndarray::ArrayView2;
+use numpy::{PyArray1, ToPyArray};
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+/// Check that the grids `x`, `y` and `z` of a surface have the same shape.
+fn check_grids(x: &ArrayView2<f64>, y: &ArrayView2<f64>, z: &ArrayView2<f64>) -> Result<()> {
+    if x.dim() != z.dim() || y.dim() != z.dim() {
+        return Err(Error::invalid_argument(format!(
+            "x, y and z grids must have the same shape, got {:?}, {:?} and {:?}",
+            x.dim(),
+            y.dim(),
+            z.dim()
+        )));
+    }
+    Ok(())
+}
+
+/// Check that the coordinates `x`, `y` and `z` of points have the same length.
+fn check_points(x: usize, y: usize, z: usize) -> Result<()> {
+    if y != x || z != x {
+        return Err(Error::invalid_argument(format!(
+            "x, y and z must have the same length, got {}, {} and {}",
+            x, y, z
+        )));
+    }
+    Ok(())
+}
+
+/// Options of [Axes3D::plot_surface].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SurfaceOptions {
+    cmap: Option<String>,
+    vmin: Option<f64>,
+    vmax: Option<f64>,
+    color: Option<Color>,
+    edge_color: Option<Color>,
+    line_width: Option<f64>,
+    stride: Option<(usize, usize)>,
+    alpha: Option<f64>,
+}
+
+impl SurfaceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Name of the colormap to color the surface by its height, e.g. `"viridis"`.
+    /// Required for a colorbar of the [Surface].
+    pub fn cmap(mut self, cmap: &str) -> Self {
+        self.cmap = Some(cmap.to_owned());
+        self
+    }
+
+    /// Range of heights covered by the colormap, open ends default to the data range.
+    pub fn range(mut self, vmin: Option<f64>, vmax: Option<f64>) -> Self {
+        self.vmin = vmin;
+        self.vmax = vmax;
+        self
+    }
+
+    /// Uniform color of the surface, used without a [SurfaceOptions::cmap].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    pub fn edge_color(mut self, color: impl Into<Color>) -> Self {
+        self.edge_color = Some(color.into());
+        self
+    }
+
+    /// Width of the patch edges in points.
+    pub fn line_width(mut self, width: f64) -> Self {
+        self.line_width = Some(width);
+        self
+    }
+
+    /// Downsample the grid to every `rows`-th row and `cols`-th column, both at least `1`.
+    pub fn stride(mut self, rows: usize, cols: usize) -> Self {
+        self.stride = Some((rows, cols));
+        self
+    }
+
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    fn kwargs<'py>(&self, py: Python<'py>) -> Result<&'py PyDict> {
+        let kwargs = PyDict::new(py);
+        if let Some(cmap) = &self.cmap {
+            kwargs.set_item("cmap", cmap)?;
+        }
+        if let Some(vmin) = self.vmin {
+            kwargs.set_item("vmin", vmin)?;
+        }
+        if let Some(vmax) = self.vmax {
+            kwargs.set_item("vmax", vmax)?;
+        }
+        if let Some(color) = &self.color {
+            kwargs.set_item("color", color.to_py(py)?)?;
+        }
+        if let Some(color) = &self.edge_color {
+            kwargs.set_item("edgecolor", color.to_py(py)?)?;
+        }
+        if let Some(width) = self.line_width {
+            kwargs.set_item("linewidth", width)?;
+        }
+        if let Some((rows, cols)) = self.stride {
+            if rows == 0 || cols == 0 {
+                return Err(Error::invalid_argument(format!(
+                    "invalid surface strides ({}, {}): expected positive values",
+                    rows, cols
+                )));
+            }
+            kwargs.set_item("rstride", rows)?;
+            kwargs.set_item("cstride", cols)?;
+        }
+        if let Some(alpha) = self.alpha {
+            check_alpha(alpha)?;
+            kwargs.set_item("alpha", alpha)?;
+        }
+        Ok(kwargs)
+    }
+}
+
+/// Options of [Axes3D::plot_wireframe].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WireframeOptions {
+    color: Option<Color>,
+    line_width: Option<f64>,
+    stride: Option<(usize, usize)>,
+    alpha: Option<f64>,
+    label: Option<String>,
+}
+
+impl WireframeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Line width in points.
+    pub fn line_width(mut self, width: f64) -> Self {
+        self.line_width = Some(width);
+        self
+    }
+
+    /// Draw only every `rows`-th row and `cols`-th column of the grid,
+    /// `0` leaves out that direction entirely.
+    pub fn stride(mut self, rows: usize, cols: usize) -> Self {
+        self.stride = Some((rows, cols));
+        self
+    }
+
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_owned());
+        self
+    }
+
+    fn kwargs<'py>(&self, py: Python<'py>) -> Result<&'py PyDict> {
+        let kwargs = PyDict::new(py);
+        if let Some(color) = &self.color {
+            kwargs.set_item("color", color.to_py(py)?)?;
+        }
+        if let Some(width) = self.line_width {
+            kwargs.set_item("linewidth", width)?;
+        }
+        if let Some((rows, cols)) = self.stride {
+            if rows == 0 && cols == 0 {
+                return Err(Error::invalid_argument(
+                    "wireframe strides must not both be zero",
+                ));
+            }
+            kwargs.set_item("rstride", rows)?;
+            kwargs.set_item("cstride", cols)?;
+        }
+        if let Some(alpha) = self.alpha {
+            check_alpha(alpha)?;
+            kwargs.set_item("alpha", alpha)?;
+        }
+        if let Some(label) = &self.label {
+            kwargs.set_item("label", label)?;
+        }
+        Ok(kwargs)
+    }
+}
+
+/// Handle to the `mpl_toolkits.mplot3d.art3d.Poly3DCollection` drawn by [Axes3D::plot_surface].
+///
+/// Only a surface drawn with a [SurfaceOptions::cmap] is colormapped and can be described by a
+/// [crate::Colorbar].
+pub struct Surface<'a> {
+    surface: &'a pyo3::types::PyAny,
+    colormapped: bool,
+}
+
+impl<'a> std::fmt::Debug for Surface<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.surface)
+    }
+}
+
+impl<'a> Surface<'a> {
+    /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
+    pub unsafe fn surface(&self) -> &'a pyo3::types::PyAny {
+        self.surface
+    }
+}
+
+impl<'a> Sealed<'a> for Surface<'a> {
+    fn mappable(&self) -> Result<&'a pyo3::types::PyAny> {
+        if !self.colormapped {
+            return Err(Error::invalid_argument(
+                "surface is not colormapped: draw it with SurfaceOptions::cmap for a colorbar",
+            ));
+        }
+        Ok(self.surface)
+    }
+}
+
+impl<'a> ScalarMappable<'a> for Surface<'a> {}
+
+/// Handle to a `mpl_toolkits.mplot3d.axes3d.Axes3D`, see [Figure::add_axes3d].
+pub struct Axes3D<'a> {
+    py: Python<'a>,
+    axes: &'a pyo3::types::PyAny,
+}
+
+impl<'a> std::fmt::Debug for Axes3D<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.axes)
+    }
+}
+
+impl<'a> Axes3D<'a> {
+    /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
+    pub unsafe fn ax(&self) -> &'a pyo3::types::PyAny {
+        self.axes
+    }
+
+    /// The same axes as [Axes], e.g. for the title, x/y labels and limits or a legend.
+    pub fn as_axes(&self) -> Axes<'a> {
+        Axes {
+            py: self.py,
+            axes: self.axes,
+        }
+    }
+
+    /// Plot a surface through the points `(x[[i, j]], y[[i, j]], z[[i, j]])`,
+    /// e.g. on grids from a meshgrid.
+    /// See `mpl_toolkits.mplot3d.axes3d.Axes3D.plot_surface` for more details.
+    pub fn plot_surface(
+        &self,
+        x: ArrayView2<f64>,
+        y: ArrayView2<f64>,
+        z: ArrayView2<f64>,
+        options: &SurfaceOptions,
+    ) -> Result<Surface<'a>> {
+        check_grids(&x, &y, &z)?;
+        let surface = self.axes.call_method(
+            "plot_surface",
+            (
+                x.to_pyarray(self.py),
+                y.to_pyarray(self.py),
+                z.to_pyarray(self.py),
+            ),
+            Some(options.kwargs(self.py)?),
+        )?;
+        Ok(Surface {
+            surface,
+            colormapped: options.cmap.is_some(),
+        })
+    }
+
+    /// Plot the grid lines of a surface through the points `(x[[i, j]], y[[i, j]], z[[i, j]])`.
+    /// See `mpl_toolkits.mplot3d.axes3d.Axes3D.plot_wireframe` for more details.
+    pub fn plot_wireframe(
+        &self,
+        x: ArrayView2<f64>,
+        y: ArrayView2<f64>,
+        z: ArrayView2<f64>,
+        options: &WireframeOptions,
+    ) -> Result<&Self> {
+        check_grids(&x, &y, &z)?;
+        self.axes.call_method(
+            "plot_wireframe",
+            (
+                x.to_pyarray(self.py),
+                y.to_pyarray(self.py),
+                z.to_pyarray(self.py),
+            ),
+            Some(options.kwargs(self.py)?),
+        )?;
+        Ok(self)
+    }
+
+    /// Draw a marker at each point `(x[i], y[i], z[i])`.
+    /// See `mpl_toolkits.mplot3d.axes3d.Axes3D.scatter` for more details.
+    pub fn scatter3d<I, J, K, F, G, H>(
+        &self,
+        x: I,
+        y: J,
+        z: K,
+        options: &ScatterOptions,
+    ) -> Result<&Self>
+    where
+        I: IntoIterator<Item = F>,
+        J: IntoIterator<Item = G>,
+        K: IntoIterator<Item = H>,
+        F: numpy::Element,
+        G: numpy::Element,
+        H: numpy::Element,
+    {
+        let x: &PyArray1<F> = PyArray1::from_iter(self.py, x);
+        let y: &PyArray1<G> = PyArray1::from_iter(self.py, y);
+        let z: &PyArray1<H> = PyArray1::from_iter(self.py, z);
+        check_points(x.len(), y.len(), z.len())?;
+        let kwargs = options.kwargs(self.py, x.len())?;
+        self.axes.call_method("scatter", (x, y, z), Some(kwargs))?;
+        Ok(self)
+    }
+
+    /// Draw a line through the points `(x[i], y[i], z[i])`.
+    /// See `mpl_toolkits.mplot3d.axes3d.Axes3D.plot` for more details.
+    pub fn plot3d<I, J, K, F, G, H>(&self, x: I, y: J, z: K, options: &LineOptions) -> Result<&Self>
+    where
+        I: IntoIterator<Item = F>,
+        J: IntoIterator<Item = G>,
+        K: IntoIterator<Item = H>,
+        F: numpy::Element,
+        G: numpy::Element,
+        H: numpy::Element,
+    {
+        let x: &PyArray1<F> = PyArray1::from_iter(self.py, x);
+        let y: &PyArray1<G> = PyArray1::from_iter(self.py, y);
+        let z: &PyArray1<H> = PyArray1::from_iter(self.py, z);
+        check_points(x.len(), y.len(), z.len())?;
+        self.axes
+            .call_method("plot", (x, y, z), Some(options.kwargs(self.py)?))?;
+        Ok(self)
+    }
+
+    pub fn set_zlabel(&self, label: &str) -> Result<Text<'a>> {
+        let text = self.axes.call_method1("set_zlabel", (label,))?;
+        Ok(Text { text })
+    }
+
+    /// Set the z limits, `None` keeps the current value of that end.
+    pub fn set_zlim(&self, bottom: Option<f64>, top: Option<f64>) -> Result<&Self> {
+        let kwargs = PyDict::new(self.py);
+        kwargs.set_item("bottom", bottom)?;
+        kwargs.set_item("top", top)?;
+        self.axes.call_method("set_zlim", (), Some(kwargs))?;
+        Ok(self)
+    }
+
+    /// The z limits as `(bottom, top)`.
+    pub fn get_zlim(&self) -> Result<(f64, f64)> {
+        Ok(self.axes.call_method0("get_zlim")?.extract()?)
+    }
+
+    /// Set the camera to the elevation `elev` above the x-y plane
+    /// and the azimuth `azim` around the z axis, both in degrees.
+    pub fn view_init(&self, elev: f64, azim: f64) -> Result<&Self> {
+        self.axes.call_method1("view_init", (elev, azim))?;
+        Ok(self)
+    }
+}
+
+impl<'a> Axes<'a> {
+    /// These axes as [Axes3D], if they were created with [Projection::ThreeD].
+    pub fn as_axes3d(&self) -> Result<Axes3D<'a>> {
+        let name: String = self.axes.getattr("name")?.extract()?;
+        if name != Projection::ThreeD.as_str() {
+            return Err(Error::invalid_argument(format!(
+                "expected 3d axes, got {} axes",
+                name
+            )));
+        }
+        Ok(Axes3D {
+            py: self.py,
+            axes: self.axes,
+        })
+    }
+}
+
+impl<'a> Figure<'a> {
+    /// Add 3D axes filling the figure.
+    pub fn add_axes3d(&self) -> Result<Axes3D<'a>> {
+        Projection::ThreeD.register(self.py)?;
+        let kwargs = PyDict::new(self.py);
+        kwargs.set_item("projection", Projection::ThreeD)?;
+        let axes = self.fig.call_method("add_subplot", (), Some(kwargs))?;
+        Ok(Axes3D { py: self.py, axes })
+    }
+}
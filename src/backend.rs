@@ -0,0 +1,110 @@
+use crate::{Error, PyPlot, Result};
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+/// matplotlib backends, see `matplotlib.use`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// Raster images without a display, the headless default.
+    Agg,
+    Svg,
+    Pdf,
+    Qt5Agg,
+    TkAgg,
+    /// Any other backend by name, e.g. `"module://my_backend"`.
+    Custom(String),
+}
+
+impl Backend {
+    /// The backend name as understood by `matplotlib.use`.
+    pub fn name(&self) -> &str {
+        match self {
+            Backend::Agg => "Agg",
+            Backend::Svg => "svg",
+            Backend::Pdf => "pdf",
+            Backend::Qt5Agg => "Qt5Agg",
+            Backend::TkAgg => "TkAgg",
+            Backend::Custom(name) => name,
+        }
+    }
+}
+
+impl std::fmt::Display for Backend {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Whether a display is available to interactive backends.
+///
+/// Only X11 and Wayland sessions are detected, other platforms are assumed to have a display.
+pub fn has_display() -> bool {
+    if cfg!(all(unix, not(target_os = "macos"))) {
+        ["DISPLAY", "WAYLAND_DISPLAY"]
+            .iter()
+            .any(|var| std::env::var_os(var).is_some_and(|v| !v.is_empty()))
+    } else {
+        true
+    }
+}
+
+/// Whether the backend is still matplotlib's automatic default,
+/// i.e. not chosen by `MPLBACKEND`, a `matplotlibrc` or an earlier `matplotlib.use`.
+pub(crate) fn is_auto_backend(py: Python) -> Result<bool> {
+    let matplotlib = py.import("matplotlib")?;
+    let rc_params = matplotlib.getattr("rcParams")?;
+    if rc_params.hasattr("_get_backend_or_none")? {
+        return Ok(rc_params.call_method0("_get_backend_or_none")?.is_none());
+    }
+    let rcsetup = py.import("matplotlib.rcsetup")?;
+    if !rcsetup.hasattr("_auto_backend_sentinel")? {
+        return Ok(false);
+    }
+    // Bypass `RcParams.__getitem__`, which resolves the sentinel to a backend.
+    let backend = py
+        .import("builtins")?
+        .getattr("dict")?
+        .call_method1("__getitem__", (rc_params, "backend"))?;
+    Ok(backend.is(rcsetup.getattr("_auto_backend_sentinel")?))
+}
+
+/// Select `backend`, with `force` also switching away from an already loaded backend.
+pub(crate) fn use_backend(py: Python, backend: &Backend, force: bool) -> Result<()> {
+    let matplotlib = py.import("matplotlib")?;
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("force", force)?;
+    matplotlib
+        .call_method("use", (backend.name(),), Some(kwargs))
+        .map_err(|err| Error::backend(backend.name(), err))?;
+    Ok(())
+}
+
+impl<'a> PyPlot<'a> {
+    /// Like [PyPlot::with_plt], with `backend` selected before `matplotlib.pyplot` is imported.
+    pub fn with_backend<F, R, E>(backend: Backend, f: F) -> Result<R, E>
+    where
+        F: FnOnce(PyPlot<'_>) -> Result<R, E>,
+        E: From<Error>,
+    {
+        Python::with_gil(|py| {
+            let plt = PyPlot::new_with_backend(py, &backend)?;
+            f(plt)
+        })
+    }
+
+    /// Like [PyPlot::new], with `backend` selected before `matplotlib.pyplot` is imported.
+    pub fn new_with_backend(py: Python<'a>, backend: &Backend) -> Result<Self> {
+        use_backend(py, backend, true)?;
+        let plt = py.import("matplotlib.pyplot")?;
+        Ok(Self { py, plt })
+    }
+
+    /// Name of the active backend.
+    pub fn backend(&self) -> Result<String> {
+        Ok(self
+            .py
+            .import("matplotlib")?
+            .call_method0("get_backend")?
+            .extract()?)
+    }
+}
@@ -0,0 +1,104 @@
+//! Python callables backed by Rust closures.
+//!
+//! The closures are owned by the Python objects, so they live as long as matplotlib holds on
+//! to them. Errors returned by a closure are raised as `ValueError`. Panics are caught at the
+//! boundary and raised as `RuntimeError` carrying the panic message, so the rendering call
+//! (e.g. `savefig`) returns [crate::Error::Python] instead of resuming the panic.
+
+use numpy::{IntoPyArray, PyArrayDyn};
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+
+/// Run the closure `f`, raising a panic as `RuntimeError` with the panic message.
+///
+/// pyo3 would raise it as `PanicException` instead, which resumes the panic as soon as
+/// the error is fetched on the Rust side.
+fn catch_panic<T>(f: impl FnOnce() -> PyResult<T>) -> PyResult<T> {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic payload".to_owned());
+        Err(PyRuntimeError::new_err(format!(
+            "Rust callback panicked: {}",
+            message
+        )))
+    })
+}
+
+type FormatFn = dyn Fn(f64, Option<usize>) -> Result<String, String>;
+
+/// Callable `(value, pos) -> str` as expected by `matplotlib.ticker.FuncFormatter`.
+#[pyclass(unsendable)]
+pub(crate) struct TickFormatter {
+    f: Box<FormatFn>,
+}
+
+impl TickFormatter {
+    pub(crate) fn new<F>(f: F) -> Self
+    where
+        F: Fn(f64, Option<usize>) -> Result<String, String> + 'static,
+    {
+        Self { f: Box::new(f) }
+    }
+}
+
+#[pymethods]
+impl TickFormatter {
+    fn __call__(&self, value: f64, pos: Option<usize>) -> PyResult<String> {
+        catch_panic(|| (self.f)(value, pos).map_err(PyValueError::new_err))
+    }
+}
+
+/// Callable mapping a scalar or array element-wise through `f`, preserving its shape,
+/// as expected by the `functions` of `matplotlib.axes.Axes.secondary_xaxis`.
+#[pyclass(unsendable)]
+pub(crate) struct ElementwiseFn {
+    f: Box<dyn Fn(f64) -> f64>,
+}
+
+impl ElementwiseFn {
+    pub(crate) fn new<F>(f: F) -> Self
+    where
+        F: Fn(f64) -> f64 + 'static,
+    {
+        Self { f: Box::new(f) }
+    }
+}
+
+#[pymethods]
+impl ElementwiseFn {
+    fn __call__<'py>(&self, py: Python<'py>, x: &'py PyAny) -> PyResult<&'py PyArrayDyn<f64>> {
+        let kwargs = [("dtype", "float64")].into_py_dict(py);
+        let x: &PyArrayDyn<f64> = py
+            .import("numpy")?
+            .call_method("asarray", (x,), Some(kwargs))?
+            .downcast()?;
+        let y = catch_panic(|| Ok(x.readonly().as_array().mapv(|v| (self.f)(v))))?;
+        Ok(y.into_pyarray(py))
+    }
+}
+
+/// Callable `(pct) -> str` labelling a pie wedge by its percentage of the whole,
+/// as expected by the `autopct` of `matplotlib.axes.Axes.pie`.
+#[pyclass(unsendable)]
+pub(crate) struct PercentFormatter {
+    f: Rc<dyn Fn(f64) -> String>,
+}
+
+impl PercentFormatter {
+    pub(crate) fn new(f: Rc<dyn Fn(f64) -> String>) -> Self {
+        Self { f }
+    }
+}
+
+#[pymethods]
+impl PercentFormatter {
+    fn __call__(&self, pct: f64) -> PyResult<String> {
+        catch_panic(|| Ok((self.f)(pct)))
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{Error, ParseError, Result};
+use pyo3::{PyObject, Python, ToPyObject};
+use std::str::FromStr;
+
+/// Base colors addressable by a single letter.
+const BASE_COLORS: &[&str] = &["b", "g", "r", "c", "m", "y", "k", "w"];
+
+/// The Tableau palette behind matplotlib's default color cycle, without the `tab:` prefix.
+const TABLEAU_COLORS: &[&str] = &[
+    "blue", "orange", "green", "red", "purple", "brown", "pink", "gray", "grey", "olive", "cyan",
+];
+
+/// CSS4 color names as known to `matplotlib.colors.CSS4_COLORS`.
+const CSS4_COLORS: &[&str] = &[
+    "aliceblue",
+    "antiquewhite",
+    "aqua",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "brown",
+    "burlywood",
+    "cadetblue",
+    "chartreuse",
+    "chocolate",
+    "coral",
+    "cornflowerblue",
+    "cornsilk",
+    "crimson",
+    "cyan",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "deeppink",
+    "deepskyblue",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "firebrick",
+    "floralwhite",
+    "forestgreen",
+    "fuchsia",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "goldenrod",
+    "gray",
+    "green",
+    "greenyellow",
+    "grey",
+    "honeydew",
+    "hotpink",
+    "indianred",
+    "indigo",
+    "ivory",
+    "khaki",
+    "lavender",
+    "lavenderblush",
+    "lawngreen",
+    "lemonchiffon",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lime",
+    "limegreen",
+    "linen",
+    "magenta",
+    "maroon",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "moccasin",
+    "navajowhite",
+    "navy",
+    "oldlace",
+    "olive",
+    "olivedrab",
+    "orange",
+    "orangered",
+    "orchid",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "papayawhip",
+    "peachpuff",
+    "peru",
+    "pink",
+    "plum",
+    "powderblue",
+    "purple",
+    "rebeccapurple",
+    "red",
+    "rosybrown",
+    "royalblue",
+    "saddlebrown",
+    "salmon",
+    "sandybrown",
+    "seagreen",
+    "seashell",
+    "sienna",
+    "silver",
+    "skyblue",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "snow",
+    "springgreen",
+    "steelblue",
+    "tan",
+    "teal",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+];
+
+/// A color specification, see `matplotlib.colors` for the accepted formats.
+///
+/// Strings are parsed with [str::parse]:
+/// - `#rrggbb` and `#rrggbbaa` hex codes,
+/// - single letter base colors (`"k"`), CSS4 names (`"steelblue"`) and `"none"`,
+/// - Tableau palette names (`"tab:orange"`),
+/// - color cycle references `"C0"` to `"C9"`.
+///
+/// Float tuples convert via [From], components are expected in `[0, 1]`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Color {
+    Rgb(f64, f64, f64),
+    Rgba(f64, f64, f64, f64),
+    /// A base, CSS4 or Tableau color name.
+    Named(String),
+    /// The n-th color of the current property cycle.
+    Cycle(u8),
+    /// The color of colormap `name` at `value`, where `value` is in `[0, 1]`.
+    Colormap {
+        name: String,
+        value: f64,
+    },
+}
+
+impl Color {
+    /// Sample the colormap `name` at `value` in `[0, 1]`.
+    pub fn from_colormap(name: &str, value: f64) -> Self {
+        Color::Colormap {
+            name: name.to_owned(),
+            value,
+        }
+    }
+
+    /// Convert to the Python value matplotlib expects: a name or an RGB(A) tuple.
+    pub fn to_py(&self, py: Python) -> Result<PyObject> {
+        let check = |components: &[f64]| {
+            if components.iter().all(|c| (0. ..=1.).contains(c)) {
+                Ok(())
+            } else {
+                Err(Error::invalid_argument(format!(
+                    "invalid color {:?}: components must be in [0, 1]",
+                    components
+                )))
+            }
+        };
+        Ok(match self {
+            Color::Rgb(r, g, b) => {
+                check(&[*r, *g, *b])?;
+                (*r, *g, *b).to_object(py)
+            }
+            Color::Rgba(r, g, b, a) => {
+                check(&[*r, *g, *b, *a])?;
+                (*r, *g, *b, *a).to_object(py)
+            }
+            Color::Named(name) => name.to_object(py),
+            Color::Cycle(n) => format!("C{}", n).to_object(py),
+            Color::Colormap { name, value } => {
+                check(&[*value])?;
+                let cmap = py
+                    .import("matplotlib.pyplot")?
+                    .call_method1("get_cmap", (name.as_str(),))?;
+                cmap.call1((*value,))?.to_object(py)
+            }
+        })
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !(hex.len() == 6 || hex.len() == 8) || !hex.is_ascii() {
+        return None;
+    }
+    let mut components = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map(|c| c as f64 / 255.));
+    let r = components.next()?.ok()?;
+    let g = components.next()?.ok()?;
+    let b = components.next()?.ok()?;
+    match components.next() {
+        Some(a) => Some(Color::Rgba(r, g, b, a.ok()?)),
+        None => Some(Color::Rgb(r, g, b)),
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let err = || ParseError::new("color", s);
+        let lower = s.trim().to_ascii_lowercase();
+        if let Some(hex) = lower.strip_prefix('#') {
+            return parse_hex(hex).ok_or_else(err);
+        }
+        if let Some(name) = lower.strip_prefix("tab:") {
+            return if TABLEAU_COLORS.contains(&name) {
+                Ok(Color::Named(lower))
+            } else {
+                Err(err())
+            };
+        }
+        if let Some(n) = lower.strip_prefix('c') {
+            if n.len() == 1 {
+                if let Ok(n) = n.parse() {
+                    return Ok(Color::Cycle(n));
+                }
+            }
+        }
+        if lower == "none" || BASE_COLORS.contains(&&*lower) || CSS4_COLORS.contains(&&*lower) {
+            return Ok(Color::Named(lower));
+        }
+        Err(err())
+    }
+}
+
+impl From<(f64, f64, f64)> for Color {
+    fn from((r, g, b): (f64, f64, f64)) -> Self {
+        Color::Rgb(r, g, b)
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for Color {
+    fn from((r, g, b, a): (f64, f64, f64, f64)) -> Self {
+        Color::Rgba(r, g, b, a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Color;
+
+    #[test]
+    fn parse_hex() {
+        assert_eq!("#ff8000".parse(), Ok(Color::Rgb(1., 128. / 255., 0.)));
+        assert_eq!(
+            "#FF800080".parse(),
+            Ok(Color::Rgba(1., 128. / 255., 0., 128. / 255.))
+        );
+        for bad in ["#12345g", "#fff", "#ff80001", "#", "#ff80é"] {
+            assert!(bad.parse::<Color>().is_err(), "{:?} parsed", bad);
+        }
+    }
+
+    #[test]
+    fn parse_names_and_cycle() {
+        assert_eq!("c".parse(), Ok(Color::Named("c".to_owned())));
+        assert_eq!("C3".parse(), Ok(Color::Cycle(3)));
+        assert_eq!("c0".parse(), Ok(Color::Cycle(0)));
+        assert_eq!("crimson".parse(), Ok(Color::Named("crimson".to_owned())));
+        assert_eq!("cyan".parse(), Ok(Color::Named("cyan".to_owned())));
+        assert_eq!("None".parse(), Ok(Color::Named("none".to_owned())));
+        assert!("c10".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn parse_tableau() {
+        assert_eq!("tab:grey".parse(), Ok(Color::Named("tab:grey".to_owned())));
+        assert_eq!("tab:Blue".parse(), Ok(Color::Named("tab:blue".to_owned())));
+        assert!("tab:crimson".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = "#12345g".parse::<Color>().unwrap_err();
+        assert_eq!(err.input(), "#12345g");
+        assert_eq!(err.to_string(), r##"invalid color: "#12345g""##);
+
+        let err = "notacolor".parse::<Color>().unwrap_err();
+        assert_eq!(err.input(), "notacolor");
+        assert_eq!(err.to_string(), r#"invalid color: "notacolor""#);
+    }
+}
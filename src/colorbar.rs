@@ -0,0 +1,164 @@
+use crate::{Axes, Figure, Result};
+use pyo3::types::{PyDict, PyList};
+use pyo3::Python;
+
+pub(crate) mod private {
+    pub trait Sealed<'a> {
+        fn mappable(&self) -> crate::Result<&'a pyo3::types::PyAny>;
+    }
+}
+
+/// Artists mapping scalar data to colors, which can be described by a [Colorbar].
+pub trait ScalarMappable<'a>: private::Sealed<'a> {}
+
+/// Handle to the `matplotlib.image.AxesImage` drawn by [Axes::heatmap].
+pub struct Image<'a> {
+    pub(crate) image: &'a pyo3::types::PyAny,
+}
+
+impl<'a> std::fmt::Debug for Image<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.image)
+    }
+}
+
+impl<'a> Image<'a> {
+    /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
+    pub unsafe fn image(&self) -> &'a pyo3::types::PyAny {
+        self.image
+    }
+
+    /// Set the data range covered by the colormap.
+    pub fn set_clim(&self, vmin: f64, vmax: f64) -> Result<&Self> {
+        self.image.call_method1("set_clim", (vmin, vmax))?;
+        Ok(self)
+    }
+
+    /// Set the colormap by name, e.g. `"viridis"`.
+    pub fn set_cmap(&self, cmap: &str) -> Result<&Self> {
+        self.image.call_method1("set_cmap", (cmap,))?;
+        Ok(self)
+    }
+}
+
+impl<'a> private::Sealed<'a> for Image<'a> {
+    fn mappable(&self) -> Result<&'a pyo3::types::PyAny> {
+        Ok(self.image)
+    }
+}
+
+impl<'a> ScalarMappable<'a> for Image<'a> {}
+
+str_enum! {
+    pub enum Orientation: "orientation" {
+        Vertical => "vertical",
+        Horizontal => "horizontal",
+    }
+}
+
+/// Options of [Figure::colorbar].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColorbarOptions {
+    label: Option<String>,
+    orientation: Option<Orientation>,
+    ticks: Option<Vec<f64>>,
+    shrink: Option<f64>,
+}
+
+impl ColorbarOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_owned());
+        self
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    pub fn ticks(mut self, ticks: &[f64]) -> Self {
+        self.ticks = Some(ticks.to_vec());
+        self
+    }
+
+    /// Fraction by which to scale the colorbar relative to the axes.
+    pub fn shrink(mut self, shrink: f64) -> Self {
+        self.shrink = Some(shrink);
+        self
+    }
+
+    pub(crate) fn kwargs<'py>(&self, py: Python<'py>) -> Result<&'py PyDict> {
+        let kwargs = PyDict::new(py);
+        if let Some(label) = &self.label {
+            kwargs.set_item("label", label)?;
+        }
+        if let Some(orientation) = self.orientation {
+            kwargs.set_item("orientation", orientation)?;
+        }
+        if let Some(ticks) = &self.ticks {
+            kwargs.set_item("ticks", PyList::new(py, ticks))?;
+        }
+        if let Some(shrink) = self.shrink {
+            kwargs.set_item("shrink", shrink)?;
+        }
+        Ok(kwargs)
+    }
+}
+
+/// Handle to a `matplotlib.colorbar.Colorbar`.
+pub struct Colorbar<'a> {
+    py: Python<'a>,
+    colorbar: &'a pyo3::types::PyAny,
+}
+
+impl<'a> Colorbar<'a> {
+    /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
+    pub unsafe fn colorbar(&self) -> &'a pyo3::types::PyAny {
+        self.colorbar
+    }
+
+    pub fn set_label(&self, label: &str) -> Result<&Self> {
+        self.colorbar.call_method1("set_label", (label,))?;
+        Ok(self)
+    }
+
+    /// The [Axes] the colorbar is drawn into.
+    pub fn ax(&self) -> Result<Axes<'a>> {
+        let axes = self.colorbar.getattr("ax")?;
+        Ok(Axes { py: self.py, axes })
+    }
+}
+
+impl<'a> Figure<'a> {
+    /// Add a colorbar describing `mappable`, taking space from `ax`.
+    ///
+    /// A [crate::Surface] only maps its values to colors when drawn with a
+    /// [crate::SurfaceOptions::cmap], without one this fails with [crate::Error::InvalidArgument].
+    /// See `matplotlib.figure.Figure.colorbar` for more details.
+    pub fn colorbar<M: ScalarMappable<'a>>(
+        &self,
+        mappable: &M,
+        ax: &Axes<'a>,
+        options: &ColorbarOptions,
+    ) -> Result<Colorbar<'a>> {
+        let kwargs = options.kwargs(self.py)?;
+        kwargs.set_item("ax", ax.axes)?;
+        let colorbar = self
+            .fig
+            .call_method("colorbar", (mappable.mappable()?,), Some(kwargs))?;
+        Ok(Colorbar {
+            py: self.py,
+            colorbar,
+        })
+    }
+}
@@ -0,0 +1,353 @@
+use crate::colorbar::private::Sealed;
+use crate::{check_alpha, Axes, Color, Error, Result, ScalarMappable, Text};
+use ndarray::{ArrayView1, ArrayView2};
+use numpy::{PyArray1, ToPyArray};
+use pyo3::types::{PyDict, PyList};
+use pyo3::Python;
+
+/// Coordinates of a grid of values with shape `(nrows, ncols)`,
+/// as taken by [Axes::contour] and [Axes::pcolormesh].
+///
+/// Without coordinates, the value at `[row, col]` is placed at `x = col`, `y = row`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridCoordinates<'v> {
+    /// Coordinates `x` of the columns and `y` of the rows.
+    Vectors {
+        x: ArrayView1<'v, f64>,
+        y: ArrayView1<'v, f64>,
+    },
+    /// Coordinates of every grid point, e.g. from a meshgrid, with the shape of the grid.
+    Mesh {
+        x: ArrayView2<'v, f64>,
+        y: ArrayView2<'v, f64>,
+    },
+}
+
+impl<'v> GridCoordinates<'v> {
+    /// Convert to the `X` and `Y` arguments of a grid of shape `(nrows, ncols)`.
+    /// With `edges`, grids of cell edges, one point larger in each dimension, are accepted too.
+    pub(crate) fn to_py<'py>(
+        self,
+        py: Python<'py>,
+        (nrows, ncols): (usize, usize),
+        edges: bool,
+    ) -> Result<(&'py pyo3::PyAny, &'py pyo3::PyAny)> {
+        let fits = |offset: usize| {
+            let shape = (nrows + offset, ncols + offset);
+            match self {
+                GridCoordinates::Vectors { x, y } => (y.len(), x.len()) == shape,
+                GridCoordinates::Mesh { x, y } => x.dim() == shape && y.dim() == shape,
+            }
+        };
+        if !(fits(0) || edges && fits(1)) {
+            return Err(Error::invalid_argument(format!(
+                "coordinates {:?} do not fit a grid of shape {:?}",
+                self,
+                (nrows, ncols)
+            )));
+        }
+        Ok(match self {
+            GridCoordinates::Vectors { x, y } => (x.to_pyarray(py), y.to_pyarray(py)),
+            GridCoordinates::Mesh { x, y } => (x.to_pyarray(py), y.to_pyarray(py)),
+        })
+    }
+}
+
+/// Contour levels of [ContourOptions::levels].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Levels {
+    /// Choose about this many levels automatically.
+    Count(usize),
+    /// Draw exactly these levels, in increasing order.
+    Values(Vec<f64>),
+}
+
+impl From<usize> for Levels {
+    fn from(count: usize) -> Self {
+        Levels::Count(count)
+    }
+}
+
+impl From<Vec<f64>> for Levels {
+    fn from(values: Vec<f64>) -> Self {
+        Levels::Values(values)
+    }
+}
+
+impl From<&[f64]> for Levels {
+    fn from(values: &[f64]) -> Self {
+        Levels::Values(values.to_vec())
+    }
+}
+
+/// Coloring of the levels; matplotlib accepts either a colormap or fixed colors, not both.
+#[derive(Debug, Clone, PartialEq)]
+enum ContourColor {
+    Cmap(String),
+    Fixed(Vec<Color>),
+}
+
+/// Options of [Axes::contour] and [Axes::contourf].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContourOptions {
+    levels: Option<Levels>,
+    color: Option<ContourColor>,
+    line_width: Option<f64>,
+    alpha: Option<f64>,
+    zorder: Option<f64>,
+}
+
+impl ContourOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of levels or explicit level values, e.g. `10` or `vec![0., 0.5, 1.]`.
+    pub fn levels(mut self, levels: impl Into<Levels>) -> Self {
+        self.levels = Some(levels.into());
+        self
+    }
+
+    /// Name of the colormap to color the levels by, e.g. `"viridis"`.
+    /// Replaces any [ContourOptions::colors].
+    pub fn cmap(mut self, cmap: &str) -> Self {
+        self.color = Some(ContourColor::Cmap(cmap.to_owned()));
+        self
+    }
+
+    /// Fixed colors, cycled through the levels. Replaces any [ContourOptions::cmap].
+    pub fn colors(mut self, colors: &[Color]) -> Self {
+        self.color = Some(ContourColor::Fixed(colors.to_vec()));
+        self
+    }
+
+    /// Width of the contour lines in points, ignored by [Axes::contourf].
+    pub fn line_width(mut self, width: f64) -> Self {
+        self.line_width = Some(width);
+        self
+    }
+
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    pub fn zorder(mut self, zorder: f64) -> Self {
+        self.zorder = Some(zorder);
+        self
+    }
+
+    fn kwargs<'py>(&self, py: Python<'py>) -> Result<&'py PyDict> {
+        let kwargs = PyDict::new(py);
+        match &self.levels {
+            Some(Levels::Count(count)) => kwargs.set_item("levels", count)?,
+            Some(Levels::Values(values)) => {
+                if values.windows(2).any(|w| w[0] >= w[1]) {
+                    return Err(Error::invalid_argument(format!(
+                        "invalid levels {:?}: expected strictly increasing values",
+                        values
+                    )));
+                }
+                kwargs.set_item("levels", PyArray1::from_slice(py, values))?;
+            }
+            None => {}
+        }
+        match &self.color {
+            Some(ContourColor::Cmap(cmap)) => kwargs.set_item("cmap", cmap)?,
+            Some(ContourColor::Fixed(colors)) => {
+                let colors = colors
+                    .iter()
+                    .map(|c| c.to_py(py))
+                    .collect::<Result<Vec<_>>>()?;
+                kwargs.set_item("colors", PyList::new(py, colors))?;
+            }
+            None => {}
+        }
+        if let Some(width) = self.line_width {
+            kwargs.set_item("linewidths", width)?;
+        }
+        if let Some(alpha) = self.alpha {
+            check_alpha(alpha)?;
+            kwargs.set_item("alpha", alpha)?;
+        }
+        if let Some(zorder) = self.zorder {
+            kwargs.set_item("zorder", zorder)?;
+        }
+        Ok(kwargs)
+    }
+}
+
+/// Options of [ContourSet::clabel].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClabelOptions {
+    levels: Option<Vec<f64>>,
+    inline: Option<bool>,
+    format: Option<String>,
+    font_size: Option<f64>,
+    colors: Option<Vec<Color>>,
+}
+
+impl ClabelOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only label these levels, a subset of [ContourSet::levels].
+    pub fn levels(mut self, levels: &[f64]) -> Self {
+        self.levels = Some(levels.to_vec());
+        self
+    }
+
+    /// Whether to remove the line beneath each label.
+    pub fn inline(mut self, inline: bool) -> Self {
+        self.inline = Some(inline);
+        self
+    }
+
+    /// Old-style format string of the level values, e.g. `"%.2f"`.
+    pub fn format(mut self, format: &str) -> Self {
+        self.format = Some(format.to_owned());
+        self
+    }
+
+    pub fn font_size(mut self, size: f64) -> Self {
+        self.font_size = Some(size);
+        self
+    }
+
+    /// Label colors, cycled through the levels. Labels take the color of their line by default.
+    pub fn colors(mut self, colors: &[Color]) -> Self {
+        self.colors = Some(colors.to_vec());
+        self
+    }
+
+    fn kwargs<'py>(&self, py: Python<'py>) -> Result<&'py PyDict> {
+        let kwargs = PyDict::new(py);
+        if let Some(levels) = &self.levels {
+            kwargs.set_item("levels", PyArray1::from_slice(py, levels))?;
+        }
+        if let Some(inline) = self.inline {
+            kwargs.set_item("inline", inline)?;
+        }
+        if let Some(format) = &self.format {
+            kwargs.set_item("fmt", format)?;
+        }
+        if let Some(size) = self.font_size {
+            kwargs.set_item("fontsize", size)?;
+        }
+        if let Some(colors) = &self.colors {
+            let colors = colors
+                .iter()
+                .map(|c| c.to_py(py))
+                .collect::<Result<Vec<_>>>()?;
+            kwargs.set_item("colors", PyList::new(py, colors))?;
+        }
+        Ok(kwargs)
+    }
+}
+
+/// Handle to the `matplotlib.contour.QuadContourSet` drawn by [Axes::contour] or [Axes::contourf].
+pub struct ContourSet<'a> {
+    py: Python<'a>,
+    contour_set: &'a pyo3::types::PyAny,
+}
+
+impl<'a> std::fmt::Debug for ContourSet<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.contour_set)
+    }
+}
+
+impl<'a> ContourSet<'a> {
+    /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
+    pub unsafe fn contour_set(&self) -> &'a pyo3::types::PyAny {
+        self.contour_set
+    }
+
+    /// The values of the drawn levels.
+    pub fn levels(&self) -> Result<Vec<f64>> {
+        Ok(self
+            .contour_set
+            .getattr("levels")?
+            .call_method0("tolist")?
+            .extract()?)
+    }
+
+    /// Label the contour lines with their level values.
+    /// See `matplotlib.contour.ContourLabeler.clabel` for more details.
+    pub fn clabel(&self, options: &ClabelOptions) -> Result<Vec<Text<'a>>> {
+        let labels = self
+            .contour_set
+            .call_method("clabel", (), Some(options.kwargs(self.py)?))?;
+        labels
+            .iter()?
+            .map(|text| Ok(Text { text: text? }))
+            .collect()
+    }
+}
+
+impl<'a> Sealed<'a> for ContourSet<'a> {
+    fn mappable(&self) -> Result<&'a pyo3::types::PyAny> {
+        Ok(self.contour_set)
+    }
+}
+
+impl<'a> ScalarMappable<'a> for ContourSet<'a> {}
+
+impl<'a> Axes<'a> {
+    fn contour_impl<F>(
+        &self,
+        cmd: &str,
+        z: ArrayView2<F>,
+        coords: Option<GridCoordinates>,
+        options: &ContourOptions,
+    ) -> Result<ContourSet<'a>>
+    where
+        F: numpy::Element,
+    {
+        let kwargs = options.kwargs(self.py)?;
+        let z_py = z.to_pyarray(self.py);
+        let contour_set = match coords {
+            Some(coords) => {
+                let (x, y) = coords.to_py(self.py, z.dim(), false)?;
+                self.axes.call_method(cmd, (x, y, z_py), Some(kwargs))?
+            }
+            None => self.axes.call_method(cmd, (z_py,), Some(kwargs))?,
+        };
+        Ok(ContourSet {
+            py: self.py,
+            contour_set,
+        })
+    }
+
+    /// Draw contour lines of the grid `z`, optionally placed at `coords`.
+    /// See `matplotlib.axes.Axes.contour` for more details.
+    pub fn contour<F>(
+        &self,
+        z: ArrayView2<F>,
+        coords: Option<GridCoordinates>,
+        options: &ContourOptions,
+    ) -> Result<ContourSet<'a>>
+    where
+        F: numpy::Element,
+    {
+        self.contour_impl("contour", z, coords, options)
+    }
+
+    /// Draw filled contours of the grid `z`, optionally placed at `coords`.
+    /// See `matplotlib.axes.Axes.contourf` for more details.
+    pub fn contourf<F>(
+        &self,
+        z: ArrayView2<F>,
+        coords: Option<GridCoordinates>,
+        options: &ContourOptions,
+    ) -> Result<ContourSet<'a>>
+    where
+        F: numpy::Element,
+    {
+        self.contour_impl("contourf", z, coords, options)
+    }
+}
@@ -0,0 +1,427 @@
+use crate::{Axes, Color, Error, Orientation, Result};
+use numpy::PyArray1;
+use pyo3::types::{PyDict, PyList};
+use pyo3::{Python, ToPyObject};
+
+/// Extent of the whiskers of [Axes::boxplot]; data beyond the whiskers are drawn as fliers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Whiskers {
+    /// Up to the furthest data point within this many interquartile ranges of the box,
+    /// 1.5 by default (Tukey's rule).
+    Iqr(f64),
+    /// At the given lower and upper percentiles, in [0, 100].
+    Percentiles(f64, f64),
+    /// At the minimum and maximum of the data, without fliers.
+    Range,
+}
+
+impl Whiskers {
+    fn to_py(self, py: Python) -> Result<pyo3::PyObject> {
+        Ok(match self {
+            Whiskers::Iqr(factor) => {
+                if factor < 0. {
+                    return Err(Error::invalid_argument(format!(
+                        "invalid whisker factor {}: expected >= 0",
+                        factor
+                    )));
+                }
+                factor.to_object(py)
+            }
+            Whiskers::Percentiles(low, high) => {
+                if !(0. ..=100.).contains(&low) || !(low..=100.).contains(&high) {
+                    return Err(Error::invalid_argument(format!(
+                        "invalid whisker percentiles ({}, {}): expected 0 <= low <= high <= 100",
+                        low, high
+                    )));
+                }
+                (low, high).to_object(py)
+            }
+            Whiskers::Range => (0., 100.).to_object(py),
+        })
+    }
+}
+
+/// Statistics of one group of an [Axes::boxplot], as computed by matplotlib.
+/// See `matplotlib.cbook.boxplot_stats` for more details.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxStats {
+    pub mean: f64,
+    pub median: f64,
+    /// First quartile, the bottom of the box.
+    pub q1: f64,
+    /// Third quartile, the top of the box.
+    pub q3: f64,
+    pub iqr: f64,
+    /// End of the lower whisker.
+    pub whisker_low: f64,
+    /// End of the upper whisker.
+    pub whisker_high: f64,
+    /// Bounds of the confidence interval of the median, drawn as notch.
+    pub ci_low: f64,
+    pub ci_high: f64,
+    /// Data points beyond the whiskers.
+    pub fliers: Vec<f64>,
+}
+
+impl BoxStats {
+    fn from_py(stats: &pyo3::PyAny) -> Result<Self> {
+        let get = |key: &str| -> Result<f64> { Ok(stats.get_item(key)?.extract()?) };
+        Ok(BoxStats {
+            mean: get("mean")?,
+            median: get("med")?,
+            q1: get("q1")?,
+            q3: get("q3")?,
+            iqr: get("iqr")?,
+            whisker_low: get("whislo")?,
+            whisker_high: get("whishi")?,
+            ci_low: get("cilo")?,
+            ci_high: get("cihi")?,
+            fliers: stats
+                .get_item("fliers")?
+                .call_method0("tolist")?
+                .extract()?,
+        })
+    }
+}
+
+/// Options of [Axes::boxplot].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BoxplotOptions {
+    labels: Option<Vec<String>>,
+    notch: Option<bool>,
+    whiskers: Option<Whiskers>,
+    show_fliers: Option<bool>,
+    show_means: Option<bool>,
+    orientation: Option<Orientation>,
+    widths: Option<f64>,
+    colors: Option<Vec<Color>>,
+}
+
+impl BoxplotOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// One tick label per group.
+    pub fn labels(mut self, labels: &[&str]) -> Self {
+        self.labels = Some(labels.iter().map(|&l| l.to_owned()).collect());
+        self
+    }
+
+    /// Whether to notch the boxes at the confidence interval of the median.
+    pub fn notch(mut self, notch: bool) -> Self {
+        self.notch = Some(notch);
+        self
+    }
+
+    pub fn whiskers(mut self, whiskers: Whiskers) -> Self {
+        self.whiskers = Some(whiskers);
+        self
+    }
+
+    /// Whether to draw the data points beyond the whiskers.
+    pub fn show_fliers(mut self, show: bool) -> Self {
+        self.show_fliers = Some(show);
+        self
+    }
+
+    pub fn show_means(mut self, show: bool) -> Self {
+        self.show_means = Some(show);
+        self
+    }
+
+    /// Draw the boxes upright (the default) or lying, passed as `vert` before matplotlib 3.10.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    /// Width of the boxes in data units, the groups are one unit apart.
+    pub fn widths(mut self, width: f64) -> Self {
+        self.widths = Some(width);
+        self
+    }
+
+    /// One fill color per box.
+    pub fn colors(mut self, colors: &[Color]) -> Self {
+        self.colors = Some(colors.to_vec());
+        self
+    }
+}
+
+/// Options of [Axes::violinplot].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ViolinOptions {
+    labels: Option<Vec<String>>,
+    orientation: Option<Orientation>,
+    widths: Option<f64>,
+    show_means: Option<bool>,
+    show_medians: Option<bool>,
+    show_extrema: Option<bool>,
+    quantiles: Option<Vec<f64>>,
+    points: Option<usize>,
+    bandwidth: Option<f64>,
+    colors: Option<Vec<Color>>,
+}
+
+impl ViolinOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// One tick label per group.
+    pub fn labels(mut self, labels: &[&str]) -> Self {
+        self.labels = Some(labels.iter().map(|&l| l.to_owned()).collect());
+        self
+    }
+
+    /// Draw the violins upright (the default) or lying, passed as `vert` before matplotlib 3.10.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    /// Maximal width of the violins in data units, the groups are one unit apart.
+    pub fn widths(mut self, width: f64) -> Self {
+        self.widths = Some(width);
+        self
+    }
+
+    pub fn show_means(mut self, show: bool) -> Self {
+        self.show_means = Some(show);
+        self
+    }
+
+    pub fn show_medians(mut self, show: bool) -> Self {
+        self.show_medians = Some(show);
+        self
+    }
+
+    /// Whether to mark the minimum and maximum, shown by default.
+    pub fn show_extrema(mut self, show: bool) -> Self {
+        self.show_extrema = Some(show);
+        self
+    }
+
+    /// Mark these quantiles in [0, 1] of every group.
+    pub fn quantiles(mut self, quantiles: &[f64]) -> Self {
+        self.quantiles = Some(quantiles.to_vec());
+        self
+    }
+
+    /// Number of points at which the kernel density estimate is evaluated.
+    pub fn points(mut self, points: usize) -> Self {
+        self.points = Some(points);
+        self
+    }
+
+    /// Bandwidth factor of the kernel density estimate, Scott's rule by default.
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        self.bandwidth = Some(bandwidth);
+        self
+    }
+
+    /// One fill color per violin.
+    pub fn colors(mut self, colors: &[Color]) -> Self {
+        self.colors = Some(colors.to_vec());
+        self
+    }
+}
+
+/// Check that per-group options have one entry per group.
+fn check_groups<T>(what: &str, values: &Option<Vec<T>>, n: usize) -> Result<()> {
+    match values {
+        Some(values) if values.len() != n => Err(Error::invalid_argument(format!(
+            "expected {} {} for {} groups, got {}",
+            n,
+            what,
+            n,
+            values.len()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// The `(major, minor)` version of a version string such as `"3.10.0rc1"`.
+fn parse_version(version: &str) -> (u32, u32) {
+    let mut numbers = version.split('.').map(|part| {
+        let digits = part
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(part.len());
+        part[..digits].parse().unwrap_or(0)
+    });
+    (numbers.next().unwrap_or(0), numbers.next().unwrap_or(0))
+}
+
+impl<'a> Axes<'a> {
+    fn groups_to_py(&self, groups: &[Vec<f64>]) -> &'a PyList {
+        PyList::new(
+            self.py,
+            groups.iter().map(|g| PyArray1::from_slice(self.py, g)),
+        )
+    }
+
+    /// Fill the patches of `artists` with one color each.
+    fn set_facecolors(&self, artists: &pyo3::PyAny, colors: &[Color]) -> Result<()> {
+        for (artist, color) in artists.iter()?.zip(colors) {
+            artist?.call_method1("set_facecolor", (color.to_py(self.py)?,))?;
+        }
+        Ok(())
+    }
+
+    /// Pass `orientation` as `orientation=`, or as `vert=` before matplotlib 3.10,
+    /// which deprecates `vert` of boxplot and violinplot.
+    fn set_orientation(&self, kwargs: &PyDict, orientation: Orientation) -> Result<()> {
+        let version: String = self
+            .py
+            .import("matplotlib")?
+            .getattr("__version__")?
+            .extract()?;
+        if parse_version(&version) >= (3, 10) {
+            kwargs.set_item("orientation", orientation)?;
+        } else {
+            kwargs.set_item("vert", orientation == Orientation::Vertical)?;
+        }
+        Ok(())
+    }
+
+    /// Label the groups at their positions `1..=n` on the categorical axis.
+    fn set_group_labels(&self, labels: &[String], orientation: Option<Orientation>) -> Result<()> {
+        let positions: Vec<usize> = (1..=labels.len()).collect();
+        let (set_ticks, set_labels) = match orientation {
+            Some(Orientation::Horizontal) => ("set_yticks", "set_yticklabels"),
+            _ => ("set_xticks", "set_xticklabels"),
+        };
+        self.axes.call_method1(set_ticks, (positions,))?;
+        self.axes
+            .call_method1(set_labels, (PyList::new(self.py, labels),))?;
+        Ok(())
+    }
+
+    /// Draw a box-and-whisker plot per group of samples, at the positions `1..=groups.len()`,
+    /// and return the statistics drawn for each group.
+    /// See `matplotlib.axes.Axes.boxplot` for more details.
+    pub fn boxplot(&self, groups: &[Vec<f64>], options: &BoxplotOptions) -> Result<Vec<BoxStats>> {
+        let n = groups.len();
+        check_groups("labels", &options.labels, n)?;
+        check_groups("colors", &options.colors, n)?;
+        let data = self.groups_to_py(groups);
+        let whis = options
+            .whiskers
+            .map(|whiskers| whiskers.to_py(self.py))
+            .transpose()?;
+
+        let kwargs = PyDict::new(self.py);
+        if let Some(notch) = options.notch {
+            kwargs.set_item("notch", notch)?;
+        }
+        if let Some(whis) = &whis {
+            kwargs.set_item("whis", whis)?;
+        }
+        if let Some(show) = options.show_fliers {
+            kwargs.set_item("showfliers", show)?;
+        }
+        if let Some(show) = options.show_means {
+            kwargs.set_item("showmeans", show)?;
+        }
+        if let Some(orientation) = options.orientation {
+            self.set_orientation(kwargs, orientation)?;
+        }
+        if let Some(width) = options.widths {
+            kwargs.set_item("widths", width)?;
+        }
+        if options.colors.is_some() {
+            kwargs.set_item("patch_artist", true)?;
+        }
+        let artists = self.axes.call_method("boxplot", (data,), Some(kwargs))?;
+        if let Some(colors) = &options.colors {
+            self.set_facecolors(artists.get_item("boxes")?, colors)?;
+        }
+        if let Some(labels) = &options.labels {
+            self.set_group_labels(labels, options.orientation)?;
+        }
+
+        let kwargs = PyDict::new(self.py);
+        if let Some(whis) = &whis {
+            kwargs.set_item("whis", whis)?;
+        }
+        self.py
+            .import("matplotlib.cbook")?
+            .call_method("boxplot_stats", (data,), Some(kwargs))?
+            .iter()?
+            .map(|stats| BoxStats::from_py(stats?))
+            .collect()
+    }
+
+    /// Draw the kernel density estimate of each group of samples as a violin,
+    /// at the positions `1..=groups.len()`.
+    /// See `matplotlib.axes.Axes.violinplot` for more details.
+    pub fn violinplot(&self, groups: &[Vec<f64>], options: &ViolinOptions) -> Result<&Self> {
+        let n = groups.len();
+        check_groups("labels", &options.labels, n)?;
+        check_groups("colors", &options.colors, n)?;
+        if let Some(empty) = groups.iter().position(|g| g.is_empty()) {
+            return Err(Error::invalid_argument(format!(
+                "cannot draw a violin of the empty group {}",
+                empty
+            )));
+        }
+
+        let kwargs = PyDict::new(self.py);
+        if let Some(orientation) = options.orientation {
+            self.set_orientation(kwargs, orientation)?;
+        }
+        if let Some(width) = options.widths {
+            kwargs.set_item("widths", width)?;
+        }
+        if let Some(show) = options.show_means {
+            kwargs.set_item("showmeans", show)?;
+        }
+        if let Some(show) = options.show_medians {
+            kwargs.set_item("showmedians", show)?;
+        }
+        if let Some(show) = options.show_extrema {
+            kwargs.set_item("showextrema", show)?;
+        }
+        if let Some(quantiles) = &options.quantiles {
+            if quantiles.iter().any(|q| !(0. ..=1.).contains(q)) {
+                return Err(Error::invalid_argument(format!(
+                    "invalid quantiles {:?}: expected values in [0, 1]",
+                    quantiles
+                )));
+            }
+            let per_group = PyList::new(self.py, (0..n).map(|_| PyList::new(self.py, quantiles)));
+            kwargs.set_item("quantiles", per_group)?;
+        }
+        if let Some(points) = options.points {
+            kwargs.set_item("points", points)?;
+        }
+        if let Some(bandwidth) = options.bandwidth {
+            kwargs.set_item("bw_method", bandwidth)?;
+        }
+        let artists =
+            self.axes
+                .call_method("violinplot", (self.groups_to_py(groups),), Some(kwargs))?;
+        if let Some(colors) = &options.colors {
+            self.set_facecolors(artists.get_item("bodies")?, colors)?;
+        }
+        if let Some(labels) = &options.labels {
+            self.set_group_labels(labels, options.orientation)?;
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_version;
+
+    #[test]
+    fn parse_matplotlib_versions() {
+        assert_eq!(parse_version("3.9.2"), (3, 9));
+        assert_eq!(parse_version("3.10.0"), (3, 10));
+        assert_eq!(parse_version("3.10.0rc1"), (3, 10));
+        assert_eq!(parse_version("3.11.0.dev123+g1234abc"), (3, 11));
+        assert!(parse_version("3.9.2") < (3, 10));
+    }
+}
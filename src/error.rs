@@ -0,0 +1,307 @@
+use pyo3::exceptions::{PyImportError, PyKeyError, PyOSError, PyTypeError, PyValueError};
+use pyo3::{PyErr, Python};
+
+/// Result type of this crate.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// A Python exception with its type name, message and formatted traceback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PythonError {
+    /// Name of the exception type, e.g. `ValueError`.
+    pub kind: String,
+    pub message: String,
+    /// The formatted traceback, if Python recorded one.
+    pub traceback: Option<String>,
+}
+
+impl PythonError {
+    fn new(py: Python, err: &PyErr) -> Self {
+        let kind = err
+            .get_type(py)
+            .name()
+            .map_or_else(|_| "<unknown>".to_owned(), str::to_owned);
+        let message = err
+            .value(py)
+            .str()
+            .map_or_else(|_| "<unprintable>".to_owned(), |s| s.to_string());
+        let traceback = err.traceback(py).and_then(|tb| tb.format().ok());
+        Self {
+            kind,
+            message,
+            traceback,
+        }
+    }
+}
+
+impl std::fmt::Display for PythonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.kind, self.message)
+    }
+}
+
+/// Modules matplotlib requires, by their import name.
+const MATPLOTLIB_MODULES: &[&str] = &[
+    "matplotlib",
+    "mpl_toolkits",
+    "numpy",
+    "PIL",
+    "contourpy",
+    "cycler",
+    "dateutil",
+    "fontTools",
+    "kiwisolver",
+    "packaging",
+    "pyparsing",
+];
+
+/// Errors of this crate.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A Python module could not be imported, see [Error::is_matplotlib_missing].
+    Import {
+        /// Name of the missing module, if known.
+        module: Option<String>,
+        python: PythonError,
+    },
+    /// An argument was rejected, either in Rust or by matplotlib
+    /// (`ValueError`, `TypeError` or `KeyError`).
+    InvalidArgument {
+        message: String,
+        python: Option<PythonError>,
+    },
+    /// The backend could not be selected.
+    Backend {
+        backend: String,
+        python: PythonError,
+    },
+    /// Reading or writing a file failed.
+    Io {
+        source: std::io::Error,
+        python: Option<PythonError>,
+    },
+    /// Any other Python exception.
+    Python(PythonError),
+}
+
+impl Error {
+    pub(crate) fn invalid_argument(message: impl Into<String>) -> Self {
+        Error::InvalidArgument {
+            message: message.into(),
+            python: None,
+        }
+    }
+
+    pub(crate) fn backend(backend: &str, err: PyErr) -> Self {
+        Python::with_gil(|py| Error::Backend {
+            backend: backend.to_owned(),
+            python: PythonError::new(py, &err),
+        })
+    }
+
+    /// Whether matplotlib or one of its required dependencies, such as numpy or Pillow,
+    /// is not installed.
+    pub fn is_matplotlib_missing(&self) -> bool {
+        match self {
+            Error::Import { module, .. } => module.as_deref().is_some_and(|module| {
+                let root = module.split('.').next().unwrap_or(module);
+                MATPLOTLIB_MODULES.contains(&root)
+            }),
+            _ => false,
+        }
+    }
+
+    /// The Python exception behind this error, if any.
+    pub fn python(&self) -> Option<&PythonError> {
+        match self {
+            Error::Import { python, .. } | Error::Backend { python, .. } => Some(python),
+            Error::InvalidArgument { python, .. } | Error::Io { python, .. } => python.as_ref(),
+            Error::Python(python) => Some(python),
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Import { python, .. } => write!(f, "import failed: {}", python),
+            Error::InvalidArgument {
+                python: Some(python),
+                ..
+            } => write!(f, "invalid argument: {}", python),
+            Error::InvalidArgument { message, .. } => write!(f, "invalid argument: {}", message),
+            Error::Backend { backend, python } => {
+                write!(f, "backend {:?} failed: {}", backend, python)
+            }
+            Error::Io { source, .. } => write!(f, "I/O error: {}", source),
+            Error::Python(python) => python.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<PyErr> for Error {
+    fn from(err: PyErr) -> Self {
+        Python::with_gil(|py| {
+            let python = PythonError::new(py, &err);
+            if err.is_instance_of::<PyImportError>(py) {
+                let module = err
+                    .value(py)
+                    .getattr("name")
+                    .and_then(|name| name.extract())
+                    .ok();
+                Error::Import { module, python }
+            } else if err.is_instance_of::<PyValueError>(py)
+                || err.is_instance_of::<PyTypeError>(py)
+                || err.is_instance_of::<PyKeyError>(py)
+            {
+                Error::InvalidArgument {
+                    message: python.message.clone(),
+                    python: Some(python),
+                }
+            } else if err.is_instance_of::<PyOSError>(py) {
+                let errno: Option<i32> = err
+                    .value(py)
+                    .getattr("errno")
+                    .and_then(|errno| errno.extract())
+                    .ok();
+                let source = match errno {
+                    Some(errno) => std::io::Error::from_raw_os_error(errno),
+                    None => std::io::Error::other(python.message.clone()),
+                };
+                Error::Io {
+                    source,
+                    python: Some(python),
+                }
+            } else {
+                Error::Python(python)
+            }
+        })
+    }
+}
+
+impl<'a> From<pyo3::PyDowncastError<'a>> for Error {
+    fn from(err: pyo3::PyDowncastError<'a>) -> Self {
+        PyErr::from(err).into()
+    }
+}
+
+impl From<crate::ParseError> for Error {
+    fn from(err: crate::ParseError) -> Self {
+        Error::invalid_argument(err.to_string())
+    }
+}
+
+impl From<ndarray::ShapeError> for Error {
+    fn from(err: ndarray::ShapeError) -> Self {
+        Error::invalid_argument(err.to_string())
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(source: std::io::Error) -> Self {
+        Error::Io {
+            source,
+            python: None,
+        }
+    }
+}
+
+impl From<Error> for PyErr {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Import { .. } => PyImportError::new_err(err.to_string()),
+            Error::InvalidArgument { .. } => PyValueError::new_err(err.to_string()),
+            Error::Io { .. } => PyOSError::new_err(err.to_string()),
+            Error::Backend { .. } | Error::Python(_) => {
+                pyo3::exceptions::PyRuntimeError::new_err(err.to_string())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, PythonError};
+
+    fn python_error(kind: &str, message: &str) -> PythonError {
+        PythonError {
+            kind: kind.to_owned(),
+            message: message.to_owned(),
+            traceback: None,
+        }
+    }
+
+    fn import_error(module: Option<&str>) -> Error {
+        Error::Import {
+            module: module.map(str::to_owned),
+            python: python_error("ModuleNotFoundError", "No module named 'x'"),
+        }
+    }
+
+    #[test]
+    fn matplotlib_missing() {
+        assert!(import_error(Some("matplotlib.pyplot")).is_matplotlib_missing());
+        assert!(import_error(Some("numpy")).is_matplotlib_missing());
+        assert!(import_error(Some("PIL")).is_matplotlib_missing());
+        assert!(!import_error(Some("scipy")).is_matplotlib_missing());
+        assert!(!import_error(None).is_matplotlib_missing());
+        assert!(!Error::invalid_argument("matplotlib").is_matplotlib_missing());
+    }
+
+    #[test]
+    fn python() {
+        let python = python_error("ValueError", "bad value");
+        let err = Error::InvalidArgument {
+            message: python.message.clone(),
+            python: Some(python.clone()),
+        };
+        assert_eq!(err.python(), Some(&python));
+        assert_eq!(
+            import_error(None).python().map(|p| p.kind.as_str()),
+            Some("ModuleNotFoundError")
+        );
+        assert_eq!(Error::invalid_argument("bad value").python(), None);
+        assert_eq!(
+            Error::from(std::io::Error::other("disk full")).python(),
+            None
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Error::invalid_argument("bad value").to_string(),
+            "invalid argument: bad value"
+        );
+        let err = Error::InvalidArgument {
+            message: "bad value".to_owned(),
+            python: Some(python_error("ValueError", "bad value")),
+        };
+        assert_eq!(err.to_string(), "invalid argument: ValueError: bad value");
+        assert_eq!(
+            import_error(Some("numpy")).to_string(),
+            "import failed: ModuleNotFoundError: No module named 'x'"
+        );
+        let err = Error::Backend {
+            backend: "TkAgg".to_owned(),
+            python: python_error("ImportError", "no tkinter"),
+        };
+        assert_eq!(
+            err.to_string(),
+            r#"backend "TkAgg" failed: ImportError: no tkinter"#
+        );
+        assert_eq!(
+            Error::Python(python_error("RuntimeError", "boom")).to_string(),
+            "RuntimeError: boom"
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{Axes, Color, Error, LineOptions, Result};
+use numpy::PyArray1;
+use pyo3::{PyObject, Python, ToPyObject};
+
+/// Error magnitudes of [Axes::errorbar], drawn at `value - lower` to `value + upper`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorValues {
+    /// The same symmetric error for all points.
+    Scalar(f64),
+    /// A symmetric error per point.
+    PerPoint(Vec<f64>),
+    /// Separate lower and upper errors per point.
+    Asymmetric { lower: Vec<f64>, upper: Vec<f64> },
+}
+
+impl ErrorValues {
+    /// Convert to the `xerr`/`yerr` argument for `n` points: a scalar, a `(n,)` or `(2, n)` array.
+    fn to_py(&self, py: Python, what: &str, n: usize) -> Result<PyObject> {
+        let check = |values: &[f64]| {
+            if values.len() != n {
+                return Err(Error::invalid_argument(format!(
+                    "expected {} {} for {} points, got {}",
+                    n,
+                    what,
+                    n,
+                    values.len()
+                )));
+            }
+            if values.iter().any(|v| *v < 0.) {
+                return Err(Error::invalid_argument(format!(
+                    "invalid {}: expected non-negative values, got {:?}",
+                    what, values
+                )));
+            }
+            Ok(())
+        };
+        Ok(match self {
+            ErrorValues::Scalar(err) => {
+                if *err < 0. {
+                    return Err(Error::invalid_argument(format!(
+                        "invalid {}: expected a non-negative value, got {}",
+                        what, err
+                    )));
+                }
+                err.to_object(py)
+            }
+            ErrorValues::PerPoint(err) => {
+                check(err)?;
+                PyArray1::from_slice(py, err).to_object(py)
+            }
+            ErrorValues::Asymmetric { lower, upper } => {
+                check(lower)?;
+                check(upper)?;
+                PyArray1::from_slice(py, &[lower.as_slice(), upper].concat())
+                    .reshape([2, n])?
+                    .to_object(py)
+            }
+        })
+    }
+}
+
+impl From<f64> for ErrorValues {
+    fn from(err: f64) -> Self {
+        ErrorValues::Scalar(err)
+    }
+}
+
+impl From<Vec<f64>> for ErrorValues {
+    fn from(err: Vec<f64>) -> Self {
+        ErrorValues::PerPoint(err)
+    }
+}
+
+impl From<&[f64]> for ErrorValues {
+    fn from(err: &[f64]) -> Self {
+        ErrorValues::PerPoint(err.to_vec())
+    }
+}
+
+impl From<(Vec<f64>, Vec<f64>)> for ErrorValues {
+    fn from((lower, upper): (Vec<f64>, Vec<f64>)) -> Self {
+        ErrorValues::Asymmetric { lower, upper }
+    }
+}
+
+/// Options of [Axes::errorbar].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ErrorbarOptions {
+    line: LineOptions,
+    cap_size: Option<f64>,
+    cap_thickness: Option<f64>,
+    error_color: Option<Color>,
+    error_width: Option<f64>,
+}
+
+impl ErrorbarOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Styling of the data line and markers, including the legend label.
+    pub fn line(mut self, line: LineOptions) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Length of the error bar caps in points.
+    pub fn cap_size(mut self, size: f64) -> Self {
+        self.cap_size = Some(size);
+        self
+    }
+
+    /// Thickness of the error bar caps in points.
+    pub fn cap_thickness(mut self, thickness: f64) -> Self {
+        self.cap_thickness = Some(thickness);
+        self
+    }
+
+    /// Color of the error bars, the line color by default.
+    pub fn error_color(mut self, color: impl Into<Color>) -> Self {
+        self.error_color = Some(color.into());
+        self
+    }
+
+    /// Line width of the error bars in points.
+    pub fn error_width(mut self, width: f64) -> Self {
+        self.error_width = Some(width);
+        self
+    }
+}
+
+/// Handle to the `matplotlib.container.ErrorbarContainer` drawn by [Axes::errorbar].
+pub struct ErrorbarContainer<'a> {
+    container: &'a pyo3::types::PyAny,
+}
+
+impl<'a> std::fmt::Debug for ErrorbarContainer<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.container)
+    }
+}
+
+impl<'a> ErrorbarContainer<'a> {
+    /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
+    pub unsafe fn container(&self) -> &'a pyo3::types::PyAny {
+        self.container
+    }
+
+    pub fn has_xerr(&self) -> Result<bool> {
+        Ok(self.container.getattr("has_xerr")?.extract()?)
+    }
+
+    pub fn has_yerr(&self) -> Result<bool> {
+        Ok(self.container.getattr("has_yerr")?.extract()?)
+    }
+
+    pub fn set_label(&self, label: &str) -> Result<&Self> {
+        self.container.call_method1("set_label", (label,))?;
+        Ok(self)
+    }
+
+    /// Remove the line and error bars from the axes.
+    pub fn remove(&self) -> Result<()> {
+        self.container.call_method0("remove")?;
+        Ok(())
+    }
+}
+
+impl<'a> Axes<'a> {
+    /// Plot `y` versus `x` with error bars in y and/or x.
+    /// See `matplotlib.axes.Axes.errorbar` for more details.
+    pub fn errorbar<I, J, F, G>(
+        &self,
+        x: I,
+        y: J,
+        yerr: Option<ErrorValues>,
+        xerr: Option<ErrorValues>,
+        options: &ErrorbarOptions,
+    ) -> Result<ErrorbarContainer<'a>>
+    where
+        I: IntoIterator<Item = F>,
+        J: IntoIterator<Item = G>,
+        F: numpy::Element,
+        G: numpy::Element,
+    {
+        let x: &PyArray1<F> = PyArray1::from_iter(self.py, x);
+        let y: &PyArray1<G> = PyArray1::from_iter(self.py, y);
+        let n = x.len();
+        if y.len() != n {
+            return Err(Error::invalid_argument(format!(
+                "x and y must have the same length, got {} and {}",
+                n,
+                y.len()
+            )));
+        }
+        let kwargs = options.line.kwargs(self.py)?;
+        if let Some(yerr) = &yerr {
+            kwargs.set_item("yerr", yerr.to_py(self.py, "y errors", n)?)?;
+        }
+        if let Some(xerr) = &xerr {
+            kwargs.set_item("xerr", xerr.to_py(self.py, "x errors", n)?)?;
+        }
+        if let Some(size) = options.cap_size {
+            kwargs.set_item("capsize", size)?;
+        }
+        if let Some(thickness) = options.cap_thickness {
+            kwargs.set_item("capthick", thickness)?;
+        }
+        if let Some(color) = &options.error_color {
+            kwargs.set_item("ecolor", color.to_py(self.py)?)?;
+        }
+        if let Some(width) = options.error_width {
+            kwargs.set_item("elinewidth", width)?;
+        }
+        let container = self.axes.call_method("errorbar", (x, y), Some(kwargs))?;
+        Ok(ErrorbarContainer { container })
+    }
+}
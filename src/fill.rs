@@ -0,0 +1,428 @@
+use crate::{check_alpha, Axes, Color, Error, LineOptions, Result};
+use ndarray::ArrayView2;
+use numpy::PyArray1;
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+str_enum! {
+    /// Where the steps of a step-wise fill are drawn relative to the data points.
+    pub enum Step: "step" {
+        /// The value at `x[i]` extends to the left, up to `x[i - 1]`.
+        Pre => "pre",
+        /// The value at `x[i]` extends to the right, up to `x[i + 1]`.
+        Post => "post",
+        /// Steps are centered between the data points.
+        Mid => "mid",
+    }
+}
+
+/// Styling of a filled area.
+///
+/// Unset options fall back to the matplotlib defaults (`rcParams`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FillOptions {
+    color: Option<Color>,
+    edge_color: Option<Color>,
+    line_width: Option<f64>,
+    hatch: Option<String>,
+    alpha: Option<f64>,
+    label: Option<String>,
+    zorder: Option<f64>,
+}
+
+impl FillOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Color of both the area and its outline.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Color of the outline, overriding [FillOptions::color].
+    pub fn edge_color(mut self, color: impl Into<Color>) -> Self {
+        self.edge_color = Some(color.into());
+        self
+    }
+
+    /// Width of the outline in points.
+    pub fn line_width(mut self, width: f64) -> Self {
+        self.line_width = Some(width);
+        self
+    }
+
+    /// Hatching pattern, e.g. `"//"` or `"x"`.
+    /// See `matplotlib.patches.Patch.set_hatch` for more details.
+    pub fn hatch(mut self, hatch: &str) -> Self {
+        self.hatch = Some(hatch.to_owned());
+        self
+    }
+
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_owned());
+        self
+    }
+
+    pub fn zorder(mut self, zorder: f64) -> Self {
+        self.zorder = Some(zorder);
+        self
+    }
+
+    pub(crate) fn kwargs<'py>(&self, py: Python<'py>) -> Result<&'py PyDict> {
+        let kwargs = PyDict::new(py);
+        if let Some(color) = &self.color {
+            kwargs.set_item("color", color.to_py(py)?)?;
+        }
+        if let Some(color) = &self.edge_color {
+            kwargs.set_item("edgecolor", color.to_py(py)?)?;
+        }
+        if let Some(width) = self.line_width {
+            kwargs.set_item("linewidth", width)?;
+        }
+        if let Some(hatch) = &self.hatch {
+            kwargs.set_item("hatch", hatch)?;
+        }
+        if let Some(alpha) = self.alpha {
+            check_alpha(alpha)?;
+            kwargs.set_item("alpha", alpha)?;
+        }
+        if let Some(label) = &self.label {
+            kwargs.set_item("label", label)?;
+        }
+        if let Some(zorder) = self.zorder {
+            kwargs.set_item("zorder", zorder)?;
+        }
+        Ok(kwargs)
+    }
+}
+
+/// Options of [Axes::fill_between] and [Axes::fill_betweenx].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FillBetweenOptions {
+    fill: FillOptions,
+    mask: Option<Vec<bool>>,
+    interpolate: Option<bool>,
+    step: Option<Step>,
+}
+
+impl FillBetweenOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fill(mut self, fill: FillOptions) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Only fill where `mask` is `true`, one entry per point (`where` in matplotlib).
+    pub fn mask(mut self, mask: &[bool]) -> Self {
+        self.mask = Some(mask.to_vec());
+        self
+    }
+
+    /// With a [FillBetweenOptions::mask], extend the filled regions up to the
+    /// interpolated crossing points of the two curves.
+    pub fn interpolate(mut self, interpolate: bool) -> Self {
+        self.interpolate = Some(interpolate);
+        self
+    }
+
+    /// Fill step-wise instead of between linearly interpolated points.
+    pub fn step(mut self, step: Step) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    fn kwargs<'py>(&self, py: Python<'py>, n: usize) -> Result<&'py PyDict> {
+        let kwargs = self.fill.kwargs(py)?;
+        if let Some(mask) = &self.mask {
+            if mask.len() != n {
+                return Err(Error::invalid_argument(format!(
+                    "expected {} mask values, got {}",
+                    n,
+                    mask.len()
+                )));
+            }
+            kwargs.set_item("where", PyArray1::from_slice(py, mask))?;
+        }
+        if let Some(interpolate) = self.interpolate {
+            kwargs.set_item("interpolate", interpolate)?;
+        }
+        if let Some(step) = self.step {
+            kwargs.set_item("step", step)?;
+        }
+        Ok(kwargs)
+    }
+}
+
+/// Options of [Axes::quantile_bands].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandOptions {
+    median: LineOptions,
+    fill: FillOptions,
+    bands: Vec<(f64, f64)>,
+}
+
+impl Default for BandOptions {
+    fn default() -> Self {
+        Self {
+            median: LineOptions::default(),
+            fill: FillOptions::default().alpha(0.25),
+            bands: vec![(0.05, 0.95), (0.25, 0.75)],
+        }
+    }
+}
+
+impl BandOptions {
+    /// Median line with 5–95% and 25–75% bands of translucent fill.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Styling of the median line, including the legend label.
+    pub fn median(mut self, median: LineOptions) -> Self {
+        self.median = median;
+        self
+    }
+
+    /// Styling of every band. Without a [FillOptions::color], bands take the median's color.
+    /// A [FillOptions::label] only goes to the first band, for a single legend entry.
+    pub fn fill(mut self, fill: FillOptions) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Pairs of `(lower, upper)` quantiles in [0, 1] bounding the bands, drawn in order.
+    pub fn bands(mut self, bands: &[(f64, f64)]) -> Self {
+        self.bands = bands.to_vec();
+        self
+    }
+}
+
+/// Quantile `q` of the non-empty `sorted` values, linearly interpolated between the closest ranks.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let h = (sorted.len() - 1) as f64 * q;
+    let lo = h.floor() as usize;
+    let hi = h.ceil() as usize;
+    sorted[lo] + (h - lo as f64) * (sorted[hi] - sorted[lo])
+}
+
+/// The columns of `samples`, each sorted for [quantile].
+///
+/// Infinite samples are rejected like NaN, as interpolating next to them yields NaN.
+fn sorted_columns(samples: ArrayView2<f64>) -> Result<Vec<Vec<f64>>> {
+    if samples.nrows() == 0 {
+        return Err(Error::invalid_argument("expected at least one draw"));
+    }
+    if samples.iter().any(|v| !v.is_finite()) {
+        return Err(Error::invalid_argument(
+            "samples must be finite, without NaN or infinity",
+        ));
+    }
+    Ok(samples
+        .columns()
+        .into_iter()
+        .map(|column| {
+            let mut column = column.to_vec();
+            column.sort_by(f64::total_cmp);
+            column
+        })
+        .collect())
+}
+
+impl<'a> Axes<'a> {
+    fn fill_between_impl<I, J, K, F, G, H>(
+        &self,
+        cmd: &str,
+        t: I,
+        v1: J,
+        v2: K,
+        options: &FillBetweenOptions,
+    ) -> Result<&Self>
+    where
+        I: IntoIterator<Item = F>,
+        J: IntoIterator<Item = G>,
+        K: IntoIterator<Item = H>,
+        F: numpy::Element,
+        G: numpy::Element,
+        H: numpy::Element,
+    {
+        let t: &PyArray1<F> = PyArray1::from_iter(self.py, t);
+        let v1: &PyArray1<G> = PyArray1::from_iter(self.py, v1);
+        let v2: &PyArray1<H> = PyArray1::from_iter(self.py, v2);
+        let n = t.len();
+        if v1.len() != n || v2.len() != n {
+            return Err(Error::invalid_argument(format!(
+                "expected curves of length {}, got {} and {}",
+                n,
+                v1.len(),
+                v2.len()
+            )));
+        }
+        let kwargs = options.kwargs(self.py, n)?;
+        self.axes.call_method(cmd, (t, v1, v2), Some(kwargs))?;
+        Ok(self)
+    }
+
+    /// Fill the area between the curves `(x, y1)` and `(x, y2)`.
+    /// See `matplotlib.axes.Axes.fill_between` for more details.
+    pub fn fill_between<I, J, K, F, G, H>(
+        &self,
+        x: I,
+        y1: J,
+        y2: K,
+        options: &FillBetweenOptions,
+    ) -> Result<&Self>
+    where
+        I: IntoIterator<Item = F>,
+        J: IntoIterator<Item = G>,
+        K: IntoIterator<Item = H>,
+        F: numpy::Element,
+        G: numpy::Element,
+        H: numpy::Element,
+    {
+        self.fill_between_impl("fill_between", x, y1, y2, options)
+    }
+
+    /// Fill the area between the curves `(x1, y)` and `(x2, y)`.
+    /// See `matplotlib.axes.Axes.fill_betweenx` for more details.
+    pub fn fill_betweenx<I, J, K, F, G, H>(
+        &self,
+        y: I,
+        x1: J,
+        x2: K,
+        options: &FillBetweenOptions,
+    ) -> Result<&Self>
+    where
+        I: IntoIterator<Item = F>,
+        J: IntoIterator<Item = G>,
+        K: IntoIterator<Item = H>,
+        F: numpy::Element,
+        G: numpy::Element,
+        H: numpy::Element,
+    {
+        self.fill_between_impl("fill_betweenx", y, x1, x2, options)
+    }
+
+    /// Plot the median of `samples` over `x` with bands between quantiles of `samples`,
+    /// e.g. the outputs of a Monte Carlo simulation.
+    ///
+    /// Each row of `samples` is one draw, each column belongs to one point of `x`,
+    /// and all samples must be finite.
+    /// Quantiles are linearly interpolated between the closest ranks, like `numpy.quantile`.
+    pub fn quantile_bands<I, F>(
+        &self,
+        x: I,
+        samples: ArrayView2<f64>,
+        options: &BandOptions,
+    ) -> Result<&Self>
+    where
+        I: IntoIterator<Item = F>,
+        F: numpy::Element,
+    {
+        let x: &PyArray1<F> = PyArray1::from_iter(self.py, x);
+        if samples.ncols() != x.len() {
+            return Err(Error::invalid_argument(format!(
+                "expected {} columns of samples, one per point, got {}",
+                x.len(),
+                samples.ncols()
+            )));
+        }
+        if let Some((lower, upper)) = options
+            .bands
+            .iter()
+            .find(|(lower, upper)| !(0. ..=1.).contains(lower) || !(0. ..=1.).contains(upper))
+        {
+            return Err(Error::invalid_argument(format!(
+                "invalid band ({}, {}): expected quantiles in [0, 1]",
+                lower, upper
+            )));
+        }
+
+        let columns = sorted_columns(samples)?;
+        let quantiles = |q: f64| -> Vec<f64> { columns.iter().map(|c| quantile(c, q)).collect() };
+
+        let median = PyArray1::from_vec(self.py, quantiles(0.5));
+        let lines =
+            self.axes
+                .call_method("plot", (x, median), Some(options.median.kwargs(self.py)?))?;
+        let color = lines.get_item(0)?.call_method0("get_color")?;
+        for (i, (lower, upper)) in options.bands.iter().enumerate() {
+            let kwargs = options.fill.kwargs(self.py)?;
+            if i > 0 && kwargs.contains("label")? {
+                kwargs.del_item("label")?;
+            }
+            if !kwargs.contains("color")? {
+                kwargs.set_item("color", color)?;
+            }
+            let lower = PyArray1::from_vec(self.py, quantiles(*lower));
+            let upper = PyArray1::from_vec(self.py, quantiles(*upper));
+            self.axes
+                .call_method("fill_between", (x, lower, upper), Some(kwargs))?;
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{quantile, sorted_columns};
+    use ndarray::{array, Array2};
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-12,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    // Expected values from `numpy.quantile` with the default linear interpolation.
+    #[test]
+    fn quantile_interpolates_like_numpy() {
+        let sorted = [1., 2., 3., 4.];
+        assert_close(quantile(&sorted, 0.25), 1.75);
+        assert_close(quantile(&sorted, 0.5), 2.5);
+        assert_close(quantile(&sorted, 0.9), 3.7);
+
+        let sorted = [0., 10., 20., 30., 40.];
+        assert_close(quantile(&sorted, 0.1), 4.);
+        assert_close(quantile(&sorted, 0.5), 20.);
+        assert_close(quantile(&sorted, 0.95), 38.);
+    }
+
+    #[test]
+    fn quantile_bounds() {
+        let sorted = [-3., 1., 2., 8.];
+        assert_close(quantile(&sorted, 0.), -3.);
+        assert_close(quantile(&sorted, 1.), 8.);
+    }
+
+    #[test]
+    fn sorted_columns_rejects_non_finite_samples() {
+        assert!(sorted_columns(array![[f64::INFINITY], [f64::INFINITY]].view()).is_err());
+        assert!(sorted_columns(array![[f64::NEG_INFINITY, 0.], [1., 0.]].view()).is_err());
+        assert!(sorted_columns(array![[f64::NAN, 0.]].view()).is_err());
+        assert!(sorted_columns(Array2::<f64>::zeros((0, 2)).view()).is_err());
+        assert_eq!(
+            sorted_columns(array![[3., 0.], [1., 2.], [2., 1.]].view()).unwrap(),
+            vec![vec![1., 2., 3.], vec![0., 1., 2.]]
+        );
+    }
+
+    #[test]
+    fn quantile_single_draw() {
+        for q in [0., 0.05, 0.5, 0.95, 1.] {
+            assert_close(quantile(&[7.], q), 7.);
+        }
+    }
+}
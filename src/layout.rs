@@ -0,0 +1,345 @@
+use crate::{Axes, Error, Figure, PyPlot, Result};
+use ndarray::Array2;
+use pyo3::types::{PyDict, PyList, PySlice};
+use pyo3::Python;
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+str_enum! {
+    /// Which subplots share an axis, see `matplotlib.pyplot.subplots`.
+    pub enum ShareAxes: "axis sharing" {
+        /// Each subplot has an independent axis.
+        None => "none",
+        /// All subplots share the axis.
+        All => "all",
+        /// Subplots in the same row share the axis.
+        Row => "row",
+        /// Subplots in the same column share the axis.
+        Col => "col",
+    }
+}
+
+str_enum! {
+    /// Kind of axes to create, see [Figure::add_subplot_with].
+    pub enum Projection: "projection" {
+        /// Cartesian axes, the default.
+        Rectilinear => "rectilinear",
+        /// Polar axes, see [crate::PolarAxes].
+        Polar => "polar",
+        /// 3D axes, see [crate::Axes3D].
+        ThreeD => "3d",
+    }
+}
+
+impl Projection {
+    /// Make the projection known to matplotlib, importing the toolkit providing it.
+    pub(crate) fn register(self, py: Python) -> Result<()> {
+        if self == Projection::ThreeD {
+            py.import("mpl_toolkits.mplot3d")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Figure<'a> {
+    /// Add a regular grid of `nrows` x `ncols` subplots.
+    ///
+    /// Unlike `matplotlib.figure.Figure.subplots` the result is never squeezed:
+    /// the returned array always has shape `(nrows, ncols)`, also for a single subplot.
+    pub fn subplots(
+        &self,
+        nrows: usize,
+        ncols: usize,
+        share_x: ShareAxes,
+        share_y: ShareAxes,
+    ) -> Result<Array2<Axes<'a>>> {
+        if nrows == 0 || ncols == 0 {
+            return Err(Error::invalid_argument(format!(
+                "invalid subplot grid {}x{}: expected at least one row and column",
+                nrows, ncols
+            )));
+        }
+        let kwargs = PyDict::new(self.py);
+        kwargs.set_item("sharex", share_x)?;
+        kwargs.set_item("sharey", share_y)?;
+        kwargs.set_item("squeeze", false)?;
+        let axs = self
+            .fig
+            .call_method("subplots", (nrows, ncols), Some(kwargs))?;
+        let axes = axs
+            .getattr("flat")?
+            .iter()?
+            .map(|axes| {
+                Ok(Axes {
+                    py: self.py,
+                    axes: axes?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Array2::from_shape_vec((nrows, ncols), axes)?)
+    }
+}
+
+impl<'a> PyPlot<'a> {
+    /// Create a new [Figure] with a grid of subplots, see [Figure::subplots].
+    pub fn subplots(
+        &self,
+        nrows: usize,
+        ncols: usize,
+        share_x: ShareAxes,
+        share_y: ShareAxes,
+    ) -> Result<(Figure<'a>, Array2<Axes<'a>>)> {
+        let fig = self.figure()?;
+        let axes = fig.subplots(nrows, ncols, share_x, share_y)?;
+        Ok((fig, axes))
+    }
+}
+
+/// Rows or columns of a [GridSpec] selected by [GridSpec::slice]:
+/// a single index or a (half-open, inclusive or unbounded) range.
+pub trait GridIndex {
+    /// The selected half-open range `start..end` within a grid of `len` cells,
+    /// `None` if it is empty or out of bounds.
+    fn bounds(&self, len: usize) -> Option<(usize, usize)>;
+}
+
+fn checked(start: usize, end: usize, len: usize) -> Option<(usize, usize)> {
+    (start < end && end <= len).then_some((start, end))
+}
+
+impl GridIndex for usize {
+    fn bounds(&self, len: usize) -> Option<(usize, usize)> {
+        checked(*self, self.checked_add(1)?, len)
+    }
+}
+
+impl GridIndex for Range<usize> {
+    fn bounds(&self, len: usize) -> Option<(usize, usize)> {
+        checked(self.start, self.end, len)
+    }
+}
+
+impl GridIndex for RangeInclusive<usize> {
+    fn bounds(&self, len: usize) -> Option<(usize, usize)> {
+        checked(*self.start(), self.end().checked_add(1)?, len)
+    }
+}
+
+impl GridIndex for RangeFrom<usize> {
+    fn bounds(&self, len: usize) -> Option<(usize, usize)> {
+        checked(self.start, len, len)
+    }
+}
+
+impl GridIndex for RangeTo<usize> {
+    fn bounds(&self, len: usize) -> Option<(usize, usize)> {
+        checked(0, self.end, len)
+    }
+}
+
+impl GridIndex for RangeToInclusive<usize> {
+    fn bounds(&self, len: usize) -> Option<(usize, usize)> {
+        checked(0, self.end.checked_add(1)?, len)
+    }
+}
+
+impl GridIndex for RangeFull {
+    fn bounds(&self, len: usize) -> Option<(usize, usize)> {
+        checked(0, len, len)
+    }
+}
+
+/// Options of [Figure::add_gridspec].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GridSpecOptions {
+    width_ratios: Option<Vec<f64>>,
+    height_ratios: Option<Vec<f64>>,
+    wspace: Option<f64>,
+    hspace: Option<f64>,
+}
+
+impl GridSpecOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Relative widths of the columns, one entry per column.
+    pub fn width_ratios(mut self, ratios: &[f64]) -> Self {
+        self.width_ratios = Some(ratios.to_vec());
+        self
+    }
+
+    /// Relative heights of the rows, one entry per row.
+    pub fn height_ratios(mut self, ratios: &[f64]) -> Self {
+        self.height_ratios = Some(ratios.to_vec());
+        self
+    }
+
+    /// Horizontal space between columns as a fraction of the average column width.
+    pub fn wspace(mut self, wspace: f64) -> Self {
+        self.wspace = Some(wspace);
+        self
+    }
+
+    /// Vertical space between rows as a fraction of the average row height.
+    pub fn hspace(mut self, hspace: f64) -> Self {
+        self.hspace = Some(hspace);
+        self
+    }
+
+    fn kwargs<'py>(&self, py: Python<'py>, nrows: usize, ncols: usize) -> Result<&'py PyDict> {
+        let kwargs = PyDict::new(py);
+        for (key, ratios, n, what) in [
+            ("width_ratios", &self.width_ratios, ncols, "columns"),
+            ("height_ratios", &self.height_ratios, nrows, "rows"),
+        ] {
+            if let Some(ratios) = ratios {
+                if ratios.len() != n {
+                    return Err(Error::invalid_argument(format!(
+                        "expected {} {} for {} {}, got {}",
+                        n,
+                        key,
+                        n,
+                        what,
+                        ratios.len()
+                    )));
+                }
+                kwargs.set_item(key, PyList::new(py, ratios))?;
+            }
+        }
+        if let Some(wspace) = self.wspace {
+            kwargs.set_item("wspace", wspace)?;
+        }
+        if let Some(hspace) = self.hspace {
+            kwargs.set_item("hspace", hspace)?;
+        }
+        Ok(kwargs)
+    }
+}
+
+/// Handle to a `matplotlib.gridspec.GridSpec`, a grid whose cells can be combined into subplots.
+pub struct GridSpec<'a> {
+    py: Python<'a>,
+    gridspec: &'a pyo3::types::PyAny,
+    nrows: usize,
+    ncols: usize,
+}
+
+/// A rectangular selection of [GridSpec] cells, see [Figure::add_subplot].
+pub struct SubplotSpec<'a> {
+    spec: &'a pyo3::types::PyAny,
+}
+
+impl<'a> GridSpec<'a> {
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Select the cells spanned by `rows` and `cols`, e.g. `gs.slice(0..2, 1)`.
+    pub fn slice<R: GridIndex, C: GridIndex>(&self, rows: R, cols: C) -> Result<SubplotSpec<'a>> {
+        let (r0, r1) = rows.bounds(self.nrows).ok_or_else(|| {
+            Error::invalid_argument(format!("invalid row selection for {} rows", self.nrows))
+        })?;
+        let (c0, c1) = cols.bounds(self.ncols).ok_or_else(|| {
+            Error::invalid_argument(format!(
+                "invalid column selection for {} columns",
+                self.ncols
+            ))
+        })?;
+        let rows = PySlice::new(self.py, r0 as isize, r1 as isize, 1);
+        let cols = PySlice::new(self.py, c0 as isize, c1 as isize, 1);
+        let spec = self.gridspec.get_item((rows, cols))?;
+        Ok(SubplotSpec { spec })
+    }
+}
+
+impl<'a> Figure<'a> {
+    /// Add a grid of `nrows` x `ncols` cells to place subplots in.
+    /// See `matplotlib.figure.Figure.add_gridspec` for more details.
+    pub fn add_gridspec(
+        &self,
+        nrows: usize,
+        ncols: usize,
+        options: &GridSpecOptions,
+    ) -> Result<GridSpec<'a>> {
+        if nrows == 0 || ncols == 0 {
+            return Err(Error::invalid_argument(format!(
+                "invalid grid {}x{}: expected at least one row and column",
+                nrows, ncols
+            )));
+        }
+        let kwargs = options.kwargs(self.py, nrows, ncols)?;
+        let gridspec = self
+            .fig
+            .call_method("add_gridspec", (nrows, ncols), Some(kwargs))?;
+        Ok(GridSpec {
+            py: self.py,
+            gridspec,
+            nrows,
+            ncols,
+        })
+    }
+
+    /// Add a subplot covering the cells selected by `spec`.
+    pub fn add_subplot(&self, spec: &SubplotSpec<'a>) -> Result<Axes<'a>> {
+        let axes = self.fig.call_method1("add_subplot", (spec.spec,))?;
+        Ok(Axes { py: self.py, axes })
+    }
+
+    /// Add a subplot of the given `projection` covering the cells selected by `spec`.
+    ///
+    /// Use [Axes::as_polar] or [Axes::as_axes3d] for the methods specific to the projection.
+    pub fn add_subplot_with(
+        &self,
+        spec: &SubplotSpec<'a>,
+        projection: Projection,
+    ) -> Result<Axes<'a>> {
+        projection.register(self.py)?;
+        let kwargs = PyDict::new(self.py);
+        kwargs.set_item("projection", projection)?;
+        let axes = self
+            .fig
+            .call_method("add_subplot", (spec.spec,), Some(kwargs))?;
+        Ok(Axes { py: self.py, axes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GridIndex;
+    use std::ops::Range;
+
+    #[test]
+    fn index_bounds() {
+        assert_eq!(0.bounds(3), Some((0, 1)));
+        assert_eq!(2.bounds(3), Some((2, 3)));
+        assert_eq!(3.bounds(3), None);
+        assert_eq!(usize::MAX.bounds(3), None);
+    }
+
+    #[test]
+    fn range_bounds() {
+        assert_eq!((0..2).bounds(3), Some((0, 2)));
+        assert_eq!((1..=2).bounds(3), Some((1, 3)));
+        assert_eq!((1..).bounds(3), Some((1, 3)));
+        assert_eq!((..2).bounds(3), Some((0, 2)));
+        assert_eq!((..=2).bounds(3), Some((0, 3)));
+        assert_eq!((..).bounds(3), Some((0, 3)));
+    }
+
+    #[test]
+    fn empty_or_out_of_bounds_ranges() {
+        assert_eq!((1..1).bounds(3), None);
+        assert_eq!(Range { start: 2, end: 1 }.bounds(3), None);
+        assert_eq!((0..4).bounds(3), None);
+        assert_eq!((0..=3).bounds(3), None);
+        assert_eq!((0..=usize::MAX).bounds(3), None);
+        assert_eq!((3..).bounds(3), None);
+        assert_eq!((..0).bounds(3), None);
+        assert_eq!((..=3).bounds(3), None);
+        assert_eq!((..).bounds(0), None);
+    }
+}
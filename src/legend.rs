@@ -0,0 +1,151 @@
+use crate::{Axes, Figure, Result};
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+str_enum! {
+    /// Placement of a [Legend], relative to its bounding box.
+    pub enum LegendLocation: "legend location" {
+        /// The location with the least overlap with the plotted data.
+        Best => "best",
+        UpperRight => "upper right",
+        UpperLeft => "upper left",
+        LowerLeft => "lower left",
+        LowerRight => "lower right",
+        Right => "right",
+        CenterLeft => "center left",
+        CenterRight => "center right",
+        LowerCenter => "lower center",
+        UpperCenter => "upper center",
+        Center => "center",
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Anchor {
+    Point(f64, f64),
+    Box(f64, f64, f64, f64),
+}
+
+/// Options of [Axes::legend] and [Figure::legend].
+///
+/// Entries are collected from the labels given to the plotting calls.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LegendOptions {
+    location: Option<LegendLocation>,
+    ncols: Option<usize>,
+    frame: Option<bool>,
+    title: Option<String>,
+    anchor: Option<Anchor>,
+}
+
+impl LegendOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn location(mut self, location: LegendLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Number of columns the entries are arranged in.
+    pub fn ncols(mut self, ncols: usize) -> Self {
+        self.ncols = Some(ncols);
+        self
+    }
+
+    /// Whether to draw a frame around the legend.
+    pub fn frame(mut self, frame: bool) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_owned());
+        self
+    }
+
+    /// Place the [LegendOptions::location] corner of the legend at `(x, y)` in axes coordinates,
+    /// e.g. `(1.05, 1.)` with [LegendLocation::UpperLeft] to put it outside on the right.
+    pub fn anchor(mut self, x: f64, y: f64) -> Self {
+        self.anchor = Some(Anchor::Point(x, y));
+        self
+    }
+
+    /// Place the legend at [LegendOptions::location] within the box `(x, y, width, height)`
+    /// in axes coordinates.
+    pub fn anchor_box(mut self, x: f64, y: f64, width: f64, height: f64) -> Self {
+        self.anchor = Some(Anchor::Box(x, y, width, height));
+        self
+    }
+
+    pub(crate) fn kwargs<'py>(&self, py: Python<'py>) -> Result<&'py PyDict> {
+        let kwargs = PyDict::new(py);
+        if let Some(location) = self.location {
+            kwargs.set_item("loc", location)?;
+        }
+        if let Some(ncols) = self.ncols {
+            kwargs.set_item("ncol", ncols)?;
+        }
+        if let Some(frame) = self.frame {
+            kwargs.set_item("frameon", frame)?;
+        }
+        if let Some(title) = &self.title {
+            kwargs.set_item("title", title)?;
+        }
+        match self.anchor {
+            Some(Anchor::Point(x, y)) => kwargs.set_item("bbox_to_anchor", (x, y))?,
+            Some(Anchor::Box(x, y, w, h)) => kwargs.set_item("bbox_to_anchor", (x, y, w, h))?,
+            None => {}
+        }
+        Ok(kwargs)
+    }
+}
+
+/// Handle to a `matplotlib.legend.Legend`.
+pub struct Legend<'a> {
+    legend: &'a pyo3::types::PyAny,
+}
+
+impl<'a> std::fmt::Debug for Legend<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.legend)
+    }
+}
+
+impl<'a> Legend<'a> {
+    /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
+    pub unsafe fn legend(&self) -> &'a pyo3::types::PyAny {
+        self.legend
+    }
+
+    pub fn set_title(&self, title: &str) -> Result<&Self> {
+        self.legend.call_method1("set_title", (title,))?;
+        Ok(self)
+    }
+}
+
+impl<'a> Axes<'a> {
+    /// Add a legend listing the labelled artists of these axes.
+    /// See `matplotlib.axes.Axes.legend` for more details.
+    pub fn legend(&self, options: &LegendOptions) -> Result<Legend<'a>> {
+        let legend = self
+            .axes
+            .call_method("legend", (), Some(options.kwargs(self.py)?))?;
+        Ok(Legend { legend })
+    }
+}
+
+impl<'a> Figure<'a> {
+    /// Add a legend listing the labelled artists of all axes of the figure.
+    /// See `matplotlib.figure.Figure.legend` for more details.
+    pub fn legend(&self, options: &LegendOptions) -> Result<Legend<'a>> {
+        let legend = self
+            .fig
+            .call_method("legend", (), Some(options.kwargs(self.py)?))?;
+        Ok(Legend { legend })
+    }
+}
@@ -1,13 +1,149 @@
-use anyhow::anyhow;
-use anyhow::Result;
 use ndarray::Dimension;
 pub use numpy;
 use numpy::{PyArray1, ToPyArray};
 pub use pyo3;
 use pyo3::types::IntoPyDict;
-use pyo3::types::PyString;
+use pyo3::types::{PyDict, PyString};
 use pyo3::Python;
-use std::path::Path;
+
+/// Define a fieldless enum whose variants map to the string values matplotlib accepts.
+///
+/// Generates `as_str`, [std::fmt::Display] and [std::str::FromStr], so invalid values are
+/// rejected in Rust with a [ParseError] instead of a Python `ValueError`.
+macro_rules! str_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident : $what:literal {
+            $($(#[$vmeta:meta])* $variant:ident => $value:literal),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($(#[$vmeta])* $variant),+
+        }
+
+        impl $name {
+            /// The value as understood by matplotlib.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value),+
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::ParseError;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                match s {
+                    $($value => Ok(Self::$variant),)+
+                    _ => Err($crate::ParseError::new($what, s)),
+                }
+            }
+        }
+
+        impl pyo3::ToPyObject for $name {
+            fn to_object(&self, py: pyo3::Python) -> pyo3::PyObject {
+                self.as_str().to_object(py)
+            }
+        }
+    };
+}
+
+mod annotation;
+mod axes3d;
+mod backend;
+mod callback;
+mod color;
+mod colorbar;
+mod contour;
+mod distribution;
+mod error;
+mod errorbar;
+mod fill;
+mod layout;
+mod legend;
+mod mesh;
+mod pie;
+mod polar;
+mod rc;
+mod save;
+mod scale;
+mod scatter;
+mod style;
+mod text;
+mod ticker;
+mod twin;
+
+pub use annotation::*;
+pub use axes3d::*;
+pub use backend::*;
+pub use color::*;
+pub use colorbar::*;
+pub use contour::*;
+pub use distribution::*;
+pub use error::*;
+pub use errorbar::*;
+pub use fill::*;
+pub use layout::*;
+pub use legend::*;
+pub use mesh::*;
+pub use pie::*;
+pub use polar::*;
+pub use rc::*;
+pub use save::*;
+pub use scale::*;
+pub use scatter::*;
+pub use style::*;
+pub use text::*;
+pub use ticker::*;
+
+/// Error returned when a string does not name a valid matplotlib value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    what: &'static str,
+    input: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(what: &'static str, input: &str) -> Self {
+        Self {
+            what,
+            input: input.to_owned(),
+        }
+    }
+
+    /// The rejected input.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "invalid {}: {:?}", self.what, self.input)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Check that `alpha` is a valid opacity in [0, 1].
+pub(crate) fn check_alpha(alpha: f64) -> Result<()> {
+    if !(0. ..=1.).contains(&alpha) {
+        return Err(Error::invalid_argument(format!(
+            "invalid alpha {}: expected a value in [0, 1]",
+            alpha
+        )));
+    }
+    Ok(())
+}
 
 pub trait PlotExt<'a> {
     fn plot(plt: &mut PyPlot<'a>) -> Result<()>;
@@ -20,10 +156,18 @@ pub struct PyPlot<'a> {
 }
 
 impl<'a> PyPlot<'a> {
+    /// Provide the GIL token.
+    ///
+    /// # Safety
+    /// The caller must not use the token beyond the lifetime of this [PyPlot].
     pub unsafe fn py(&self) -> Python<'a> {
         self.py
     }
 
+    /// Provide the `matplotlib.pyplot` module handle.
+    ///
+    /// # Safety
+    /// Calls made through the raw module bypass the typed wrappers of this crate.
     pub unsafe fn plt(&self) -> &'a pyo3::types::PyModule {
         self.plt
     }
@@ -31,7 +175,7 @@ impl<'a> PyPlot<'a> {
     pub fn with_plt<F, R, E>(f: F) -> Result<R, E>
     where
         F: FnOnce(PyPlot<'_>) -> Result<R, E>,
-        E: From<pyo3::PyErr>,
+        E: From<Error>,
     {
         Python::with_gil(|py| {
             let plt = PyPlot::new(py)?;
@@ -39,14 +183,25 @@ impl<'a> PyPlot<'a> {
         })
     }
 
-    pub fn new(py: Python<'a>) -> Result<Self, pyo3::PyErr> {
+    /// Import `matplotlib.pyplot`.
+    ///
+    /// When no display is detected (see [has_display]) and the backend is still matplotlib's
+    /// automatic default, i.e. not chosen by `MPLBACKEND`, a `matplotlibrc` or an earlier
+    /// `matplotlib.use`, the headless [Backend::Agg] is selected so `show` cannot hang.
+    pub fn new(py: Python<'a>) -> Result<Self> {
+        if std::env::var_os("MPLBACKEND").is_none()
+            && !has_display()
+            && backend::is_auto_backend(py)?
+        {
+            backend::use_backend(py, &Backend::Agg, false)?;
+        }
         let plt = py.import("matplotlib.pyplot")?;
         Ok(Self { py, plt })
     }
 
     /// Create a new [Figure].
     /// See `matplotlib.pyplot.figure` for more details.
-    pub fn figure(&self) -> std::result::Result<Figure, pyo3::PyErr> {
+    pub fn figure(&self) -> Result<Figure<'a>> {
         let fig = self.plt.getattr("figure")?.call0()?;
         Ok(Figure { py: self.py, fig })
     }
@@ -55,7 +210,7 @@ impl<'a> PyPlot<'a> {
     ///
     /// If no current figure exists, a new one is created using figure().
     /// See also: https://matplotlib.org/3.1.1/api/_as_gen/matplotlib.pyplot.gcf.html
-    pub fn gcf(&self) -> Result<Figure> {
+    pub fn gcf(&self) -> Result<Figure<'a>> {
         let fig = self.plt.call_method0("gcf")?;
         Ok(Figure { py: self.py, fig })
     }
@@ -63,15 +218,6 @@ impl<'a> PyPlot<'a> {
     pub fn show(&self) -> Result<&'a pyo3::PyAny> {
         Ok(self.plt.getattr("show")?.call0()?)
     }
-
-    pub fn savefig<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-        if let Some(path) = path.as_ref().to_str() {
-            self.plt.call_method1("savefig", (path,))?;
-        } else {
-            return Err(anyhow!("Invalid path: {:?}", path.as_ref()));
-        }
-        Ok(())
-    }
 }
 
 pub struct Figure<'a> {
@@ -89,6 +235,9 @@ impl<'a> Figure<'a> {
     }
 
     /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
     pub unsafe fn fig(&self) -> &pyo3::types::PyAny {
         self.fig
     }
@@ -101,7 +250,7 @@ impl<'a> Figure<'a> {
         height: f64,
         share_x: Option<&'a Axes<'a>>,
         share_y: Option<&'a Axes<'a>>,
-    ) -> Result<Axes> {
+    ) -> Result<Axes<'a>> {
         let args = PyArray1::from_vec(self.py, vec![left, bottom, width, height]);
         let mut shares = vec![];
         if let Some(ax) = share_x {
@@ -119,7 +268,7 @@ impl<'a> Figure<'a> {
         })
     }
 
-    pub fn gca(&self) -> Result<Axes> {
+    pub fn gca(&self) -> Result<Axes<'a>> {
         let axes = self.fig.call_method0("gca")?;
         Ok(Axes { py: self.py, axes })
     }
@@ -134,61 +283,59 @@ pub struct Axes<'a> {
     axes: &'a pyo3::types::PyAny,
 }
 
-pub struct Text<'a> {
-    text: &'a pyo3::types::PyAny,
-}
-
-impl<'a> std::fmt::Debug for Text<'a> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self.text)
-    }
-}
-
 impl<'a> Axes<'a> {
     /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
     pub unsafe fn ax(&self) -> &pyo3::types::PyAny {
         self.axes
     }
 
-    pub fn set_title(&self, title: &str) -> Result<Text> {
+    pub fn set_title(&self, title: &str) -> Result<Text<'a>> {
         let text = self
             .axes
             .call_method1("set_title", (PyString::new(self.py, title),))?;
         Ok(Text { text })
     }
 
-    pub fn set_xlabel(&self, xlabel: &str) -> Result<Text> {
+    pub fn set_xlabel(&self, xlabel: &str) -> Result<Text<'a>> {
         let text = self
             .axes
             .call_method1("set_xlabel", (PyString::new(self.py, xlabel),))?;
         Ok(Text { text })
     }
 
-    pub fn set_ylabel(&self, ylabel: &str) -> Result<Text> {
+    pub fn set_ylabel(&self, ylabel: &str) -> Result<Text<'a>> {
         let text = self
             .axes
             .call_method1("set_ylabel", (PyString::new(self.py, ylabel),))?;
         Ok(Text { text })
     }
 
-    pub fn scatter<I, J, F, G>(&self, x: I, y: J, alpha: f64) -> Result<&Self>
+    /// Draw small dots of uniform size and color at each point, optionally labelled for the legend.
+    /// Use [Axes::scatter_with] to vary marker size and color per point.
+    pub fn scatter<I, J, F, G>(&self, x: I, y: J, alpha: f64, label: Option<&str>) -> Result<&Self>
     where
         I: IntoIterator<Item = F>,
         J: IntoIterator<Item = G>,
         F: numpy::Element,
         G: numpy::Element,
     {
+        check_alpha(alpha)?;
         let x: &PyArray1<F> = PyArray1::from_iter(self.py, x);
         let y: &PyArray1<G> = PyArray1::from_iter(self.py, y);
-        self.axes.call_method(
-            "plot",
-            (x, y, "."),
-            Some([("alpha", alpha), ("ms", 1.0)].into_py_dict(self.py)),
-        )?;
+        let kwargs = [("alpha", alpha), ("ms", 1.0)].into_py_dict(self.py);
+        if let Some(label) = label {
+            kwargs.set_item("label", label)?;
+        }
+        self.axes.call_method("plot", (x, y, "."), Some(kwargs))?;
         Ok(self)
     }
 
-    pub fn line<I, J, F, G>(&self, x: I, y: J) -> Result<&Self>
+    /// Plot `y` versus `x` as a line, styled by `options`.
+    /// See `matplotlib.axes.Axes.plot` for more details.
+    pub fn line<I, J, F, G>(&self, x: I, y: J, options: &LineOptions) -> Result<&Self>
     where
         I: IntoIterator<Item = F>,
         J: IntoIterator<Item = G>,
@@ -197,7 +344,8 @@ impl<'a> Axes<'a> {
     {
         let x: &PyArray1<F> = PyArray1::from_iter(self.py, x);
         let y: &PyArray1<G> = PyArray1::from_iter(self.py, y);
-        self.axes.call_method1("plot", (x, y))?;
+        self.axes
+            .call_method("plot", (x, y), Some(options.kwargs(self.py)?))?;
         Ok(self)
     }
 
@@ -205,23 +353,29 @@ impl<'a> Axes<'a> {
         Ok(self.axes.call_method0("show")?)
     }
 
-    pub fn hist<I, F>(&self, x: I, bins: Option<usize>) -> Result<&Self>
+    /// Plot a histogram of `x`, optionally labelled for the legend.
+    pub fn hist<I, F>(&self, x: I, bins: Option<usize>, label: Option<&str>) -> Result<&Self>
     where
         I: IntoIterator<Item = F>,
         F: numpy::Element,
     {
         let x: &PyArray1<F> = PyArray1::from_iter(self.py, x);
-        self.axes
-            .call_method("hist", (x,), Some([("bins", bins)].into_py_dict(self.py)))?;
+        let kwargs = [("bins", bins)].into_py_dict(self.py);
+        if let Some(label) = label {
+            kwargs.set_item("label", label)?;
+        }
+        self.axes.call_method("hist", (x,), Some(kwargs))?;
         Ok(self)
     }
 
+    /// Plot vertical (or `horizontal`) bars, optionally labelled for the legend.
     pub fn bar<I, F, J, G, K, H>(
         &self,
         x: I,
         height: J,
         widths: Option<K>,
         horizontal: bool,
+        label: Option<&str>,
     ) -> Result<&Self>
     where
         I: IntoIterator<Item = F>,
@@ -235,22 +389,61 @@ impl<'a> Axes<'a> {
         let bar_size = if horizontal { "height" } else { "width" };
         let x: &PyArray1<F> = PyArray1::from_iter(self.py, x);
         let h: &PyArray1<G> = PyArray1::from_iter(self.py, height);
-        let widths: Option<&PyArray1<H>> =
-            widths.map(|widths| PyArray1::from_iter(self.py, widths));
-        self.axes.call_method(
-            cmd,
-            (x, h),
-            widths.map(|widths| [(bar_size, widths)].into_py_dict(self.py)),
-        )?;
+        let kwargs = PyDict::new(self.py);
+        if let Some(widths) = widths {
+            let widths: &PyArray1<H> = PyArray1::from_iter(self.py, widths);
+            kwargs.set_item(bar_size, widths)?;
+        }
+        if let Some(label) = label {
+            kwargs.set_item("label", label)?;
+        }
+        self.axes.call_method(cmd, (x, h), Some(kwargs))?;
         Ok(self)
     }
 
-    pub fn heatmap<F, D: Dimension>(&self, z: ndarray::ArrayView<F, D>) -> Result<&Self>
+    /// Display `z` as an image, e.g. a 2D array of scalars mapped through a colormap.
+    /// See `matplotlib.axes.Axes.imshow` for more details.
+    pub fn heatmap<F, D: Dimension>(
+        &self,
+        z: ndarray::ArrayView<F, D>,
+        label: Option<&str>,
+    ) -> Result<Image<'a>>
     where
         F: numpy::Element,
     {
         let z = z.to_pyarray(self.py);
-        self.axes.call_method1("imshow", (z,))?;
-        Ok(self)
+        let image = self.axes.call_method(
+            "imshow",
+            (z,),
+            Some([("label", label)].into_py_dict(self.py)),
+        )?;
+        Ok(Image { image })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    str_enum! {
+        enum Fruit: "fruit" {
+            Apple => "apple",
+            Pear => "pear",
+        }
+    }
+
+    #[test]
+    fn str_enum_round_trip() {
+        for fruit in [Fruit::Apple, Fruit::Pear] {
+            assert_eq!(fruit.as_str().parse(), Ok(fruit));
+            assert_eq!(fruit.to_string(), fruit.as_str());
+        }
+        assert_eq!("pear".parse(), Ok(Fruit::Pear));
+    }
+
+    #[test]
+    fn str_enum_rejects_unknown_values() {
+        let err = "Apple".parse::<Fruit>().unwrap_err();
+        assert_eq!(err.input(), "Apple");
+        assert_eq!(err.to_string(), r#"invalid fruit: "Apple""#);
+        assert!("".parse::<Fruit>().is_err());
     }
 }
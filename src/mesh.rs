@@ -0,0 +1,236 @@
+use crate::colorbar::private::Sealed;
+use crate::{check_alpha, Axes, Color, Error, GridCoordinates, Result, ScalarMappable};
+use ndarray::ArrayView2;
+use numpy::ToPyArray;
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+str_enum! {
+    /// How the cells of a [QuadMesh] are colored, see `matplotlib.axes.Axes.pcolormesh`.
+    pub enum Shading: "shading" {
+        /// Uniform color per cell, coordinates are the cell edges.
+        Flat => "flat",
+        /// Uniform color per cell, coordinates are the cell centers.
+        Nearest => "nearest",
+        /// Colors interpolated between the grid points given by the coordinates.
+        Gouraud => "gouraud",
+        /// [Shading::Flat] for edge coordinates, [Shading::Nearest] otherwise.
+        Auto => "auto",
+    }
+}
+
+/// Mapping of data values to the [0, 1] range of a colormap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Norm {
+    Linear,
+    Log,
+    /// Logarithmic, except linear within `(-linthresh, linthresh)` around zero.
+    SymLog {
+        linthresh: f64,
+    },
+    /// Linear on both sides of `center`, which is mapped to the middle of the colormap.
+    TwoSlope {
+        center: f64,
+    },
+}
+
+impl Norm {
+    fn to_py<'py>(
+        self,
+        py: Python<'py>,
+        vmin: Option<f64>,
+        vmax: Option<f64>,
+    ) -> Result<&'py pyo3::PyAny> {
+        let colors = py.import("matplotlib.colors")?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("vmin", vmin)?;
+        kwargs.set_item("vmax", vmax)?;
+        Ok(match self {
+            Norm::Linear => colors.call_method("Normalize", (), Some(kwargs))?,
+            Norm::Log => colors.call_method("LogNorm", (), Some(kwargs))?,
+            Norm::SymLog { linthresh } => {
+                if linthresh <= 0. {
+                    return Err(Error::invalid_argument(format!(
+                        "invalid symlog threshold {}: expected > 0",
+                        linthresh
+                    )));
+                }
+                colors.call_method("SymLogNorm", (linthresh,), Some(kwargs))?
+            }
+            Norm::TwoSlope { center } => {
+                colors.call_method("TwoSlopeNorm", (center,), Some(kwargs))?
+            }
+        })
+    }
+}
+
+/// Options of [Axes::pcolormesh].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeshOptions {
+    shading: Option<Shading>,
+    cmap: Option<String>,
+    norm: Option<Norm>,
+    vmin: Option<f64>,
+    vmax: Option<f64>,
+    edge_color: Option<Color>,
+    line_width: Option<f64>,
+    alpha: Option<f64>,
+    zorder: Option<f64>,
+}
+
+impl MeshOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn shading(mut self, shading: Shading) -> Self {
+        self.shading = Some(shading);
+        self
+    }
+
+    /// Name of the colormap, e.g. `"viridis"`.
+    pub fn cmap(mut self, cmap: &str) -> Self {
+        self.cmap = Some(cmap.to_owned());
+        self
+    }
+
+    pub fn norm(mut self, norm: Norm) -> Self {
+        self.norm = Some(norm);
+        self
+    }
+
+    /// Range of values covered by the colormap, open ends default to the data range.
+    pub fn range(mut self, vmin: Option<f64>, vmax: Option<f64>) -> Self {
+        self.vmin = vmin;
+        self.vmax = vmax;
+        self
+    }
+
+    /// Color of the cell edges, which are not drawn by default.
+    pub fn edge_color(mut self, color: impl Into<Color>) -> Self {
+        self.edge_color = Some(color.into());
+        self
+    }
+
+    /// Width of the cell edges in points.
+    pub fn line_width(mut self, width: f64) -> Self {
+        self.line_width = Some(width);
+        self
+    }
+
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    pub fn zorder(mut self, zorder: f64) -> Self {
+        self.zorder = Some(zorder);
+        self
+    }
+
+    fn kwargs<'py>(&self, py: Python<'py>) -> Result<&'py PyDict> {
+        let kwargs = PyDict::new(py);
+        if let Some(shading) = self.shading {
+            kwargs.set_item("shading", shading)?;
+        }
+        if let Some(cmap) = &self.cmap {
+            kwargs.set_item("cmap", cmap)?;
+        }
+        match self.norm {
+            Some(norm) => kwargs.set_item("norm", norm.to_py(py, self.vmin, self.vmax)?)?,
+            None => {
+                if let Some(vmin) = self.vmin {
+                    kwargs.set_item("vmin", vmin)?;
+                }
+                if let Some(vmax) = self.vmax {
+                    kwargs.set_item("vmax", vmax)?;
+                }
+            }
+        }
+        if let Some(color) = &self.edge_color {
+            kwargs.set_item("edgecolors", color.to_py(py)?)?;
+        }
+        if let Some(width) = self.line_width {
+            kwargs.set_item("linewidth", width)?;
+        }
+        if let Some(alpha) = self.alpha {
+            check_alpha(alpha)?;
+            kwargs.set_item("alpha", alpha)?;
+        }
+        if let Some(zorder) = self.zorder {
+            kwargs.set_item("zorder", zorder)?;
+        }
+        Ok(kwargs)
+    }
+}
+
+/// Handle to the `matplotlib.collections.QuadMesh` drawn by [Axes::pcolormesh].
+pub struct QuadMesh<'a> {
+    mesh: &'a pyo3::types::PyAny,
+}
+
+impl<'a> std::fmt::Debug for QuadMesh<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.mesh)
+    }
+}
+
+impl<'a> QuadMesh<'a> {
+    /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
+    pub unsafe fn mesh(&self) -> &'a pyo3::types::PyAny {
+        self.mesh
+    }
+
+    /// Set the data range covered by the colormap.
+    pub fn set_clim(&self, vmin: f64, vmax: f64) -> Result<&Self> {
+        self.mesh.call_method1("set_clim", (vmin, vmax))?;
+        Ok(self)
+    }
+
+    /// Set the colormap by name, e.g. `"viridis"`.
+    pub fn set_cmap(&self, cmap: &str) -> Result<&Self> {
+        self.mesh.call_method1("set_cmap", (cmap,))?;
+        Ok(self)
+    }
+}
+
+impl<'a> Sealed<'a> for QuadMesh<'a> {
+    fn mappable(&self) -> Result<&'a pyo3::types::PyAny> {
+        Ok(self.mesh)
+    }
+}
+
+impl<'a> ScalarMappable<'a> for QuadMesh<'a> {}
+
+impl<'a> Axes<'a> {
+    /// Draw the grid `c` as colored quadrilaterals, optionally placed at `coords`.
+    ///
+    /// Unlike [Axes::heatmap], cells may be non-uniform, e.g. on log-spaced coordinates.
+    /// Coordinates either give the cell edges, one larger than `c` in each dimension,
+    /// or the cell centers with the shape of `c`, see [Shading].
+    /// See `matplotlib.axes.Axes.pcolormesh` for more details.
+    pub fn pcolormesh<F>(
+        &self,
+        c: ArrayView2<F>,
+        coords: Option<GridCoordinates>,
+        options: &MeshOptions,
+    ) -> Result<QuadMesh<'a>>
+    where
+        F: numpy::Element,
+    {
+        let kwargs = options.kwargs(self.py)?;
+        let c_py = c.to_pyarray(self.py);
+        let mesh = match coords {
+            Some(coords) => {
+                let (x, y) = coords.to_py(self.py, c.dim(), true)?;
+                self.axes
+                    .call_method("pcolormesh", (x, y, c_py), Some(kwargs))?
+            }
+            None => self.axes.call_method("pcolormesh", (c_py,), Some(kwargs))?,
+        };
+        Ok(QuadMesh { mesh })
+    }
+}
@@ -0,0 +1,299 @@
+use crate::callback::PercentFormatter;
+use crate::{Axes, Color, Error, Result, Text};
+use pyo3::types::{PyDict, PyList};
+use pyo3::{Py, Python};
+use std::rc::Rc;
+
+/// Labels of the wedges with their percentage, see [PieOptions::autopct].
+#[derive(Clone)]
+enum AutoPct {
+    Format(String),
+    Fn(Rc<dyn Fn(f64) -> String>),
+}
+
+impl std::fmt::Debug for AutoPct {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AutoPct::Format(format) => f.debug_tuple("Format").field(format).finish(),
+            AutoPct::Fn(_) => f.write_str("Fn(..)"),
+        }
+    }
+}
+
+impl PartialEq for AutoPct {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AutoPct::Format(a), AutoPct::Format(b)) => a == b,
+            (AutoPct::Fn(a), AutoPct::Fn(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// Options of [Axes::pie].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PieOptions {
+    autopct: Option<AutoPct>,
+    explode: Option<Vec<f64>>,
+    start_angle: Option<f64>,
+    counterclock: Option<bool>,
+    wedge_width: Option<f64>,
+    radius: Option<f64>,
+    colors: Option<Vec<Color>>,
+    edge_color: Option<Color>,
+    pct_distance: Option<f64>,
+    label_distance: Option<f64>,
+}
+
+impl PieOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Label each wedge with its percentage, formatted by the old-style format string `format`,
+    /// e.g. `"%.1f%%"`.
+    pub fn autopct(mut self, format: &str) -> Self {
+        self.autopct = Some(AutoPct::Format(format.to_owned()));
+        self
+    }
+
+    /// Label each wedge with `f(pct)`, where `pct` is its percentage of the whole in [0, 100].
+    pub fn autopct_with<F>(mut self, f: F) -> Self
+    where
+        F: Fn(f64) -> String + 'static,
+    {
+        self.autopct = Some(AutoPct::Fn(Rc::new(f)));
+        self
+    }
+
+    /// Offset of each wedge from the center, as a fraction of the radius.
+    pub fn explode(mut self, offsets: &[f64]) -> Self {
+        self.explode = Some(offsets.to_vec());
+        self
+    }
+
+    /// Angle in degrees, counterclockwise from the x axis, at which the first wedge starts.
+    pub fn start_angle(mut self, angle: f64) -> Self {
+        self.start_angle = Some(angle);
+        self
+    }
+
+    /// Whether the wedges follow each other counterclockwise, the default, or clockwise.
+    pub fn counterclock(mut self, counterclock: bool) -> Self {
+        self.counterclock = Some(counterclock);
+        self
+    }
+
+    /// Radial width of the wedges as a fraction of the radius, below 1 for a donut chart.
+    pub fn wedge_width(mut self, width: f64) -> Self {
+        self.wedge_width = Some(width);
+        self
+    }
+
+    pub fn radius(mut self, radius: f64) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    /// One color per wedge.
+    pub fn colors(mut self, colors: &[Color]) -> Self {
+        self.colors = Some(colors.to_vec());
+        self
+    }
+
+    /// Color of the wedge outlines, e.g. white to separate the wedges.
+    pub fn edge_color(mut self, color: impl Into<Color>) -> Self {
+        self.edge_color = Some(color.into());
+        self
+    }
+
+    /// Distance of the [PieOptions::autopct] labels from the center, relative to the radius.
+    pub fn pct_distance(mut self, distance: f64) -> Self {
+        self.pct_distance = Some(distance);
+        self
+    }
+
+    /// Distance of the wedge labels from the center, relative to the radius.
+    pub fn label_distance(mut self, distance: f64) -> Self {
+        self.label_distance = Some(distance);
+        self
+    }
+
+    fn kwargs<'py>(&self, py: Python<'py>, n: usize) -> Result<&'py PyDict> {
+        let check = |what: &str, len: usize| {
+            if len != n {
+                return Err(Error::invalid_argument(format!(
+                    "expected {} {} for {} wedges, got {}",
+                    n, what, n, len
+                )));
+            }
+            Ok(())
+        };
+        let kwargs = PyDict::new(py);
+        match &self.autopct {
+            Some(AutoPct::Format(format)) => kwargs.set_item("autopct", format)?,
+            Some(AutoPct::Fn(f)) => {
+                let f = Py::new(py, PercentFormatter::new(f.clone()))?;
+                kwargs.set_item("autopct", f)?;
+            }
+            None => {}
+        }
+        if let Some(explode) = &self.explode {
+            check("explode offsets", explode.len())?;
+            kwargs.set_item("explode", PyList::new(py, explode))?;
+        }
+        if let Some(angle) = self.start_angle {
+            kwargs.set_item("startangle", angle)?;
+        }
+        if let Some(counterclock) = self.counterclock {
+            kwargs.set_item("counterclock", counterclock)?;
+        }
+        if let Some(radius) = self.radius {
+            kwargs.set_item("radius", radius)?;
+        }
+        if let Some(colors) = &self.colors {
+            check("colors", colors.len())?;
+            let colors = colors
+                .iter()
+                .map(|c| c.to_py(py))
+                .collect::<Result<Vec<_>>>()?;
+            kwargs.set_item("colors", PyList::new(py, colors))?;
+        }
+        let wedge_props = PyDict::new(py);
+        if let Some(width) = self.wedge_width {
+            if !(width > 0. && width <= 1.) {
+                return Err(Error::invalid_argument(format!(
+                    "invalid wedge width {}: expected a value in (0, 1]",
+                    width
+                )));
+            }
+            wedge_props.set_item("width", width * self.radius.unwrap_or(1.))?;
+        }
+        if let Some(color) = &self.edge_color {
+            wedge_props.set_item("edgecolor", color.to_py(py)?)?;
+        }
+        if !wedge_props.is_empty() {
+            kwargs.set_item("wedgeprops", wedge_props)?;
+        }
+        if let Some(distance) = self.pct_distance {
+            kwargs.set_item("pctdistance", distance)?;
+        }
+        if let Some(distance) = self.label_distance {
+            kwargs.set_item("labeldistance", distance)?;
+        }
+        Ok(kwargs)
+    }
+}
+
+/// Handle to a `matplotlib.patches.Wedge` of a [PieChart].
+pub struct Wedge<'a> {
+    wedge: &'a pyo3::types::PyAny,
+}
+
+impl<'a> std::fmt::Debug for Wedge<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.wedge)
+    }
+}
+
+impl<'a> Wedge<'a> {
+    /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
+    pub unsafe fn wedge(&self) -> &'a pyo3::types::PyAny {
+        self.wedge
+    }
+
+    /// Start and end angle of the wedge in degrees, counterclockwise from the x axis.
+    pub fn angles(&self) -> Result<(f64, f64)> {
+        let theta1 = self.wedge.getattr("theta1")?.extract()?;
+        let theta2 = self.wedge.getattr("theta2")?.extract()?;
+        Ok((theta1, theta2))
+    }
+
+    pub fn set_face_color(&self, color: impl Into<Color>) -> Result<&Self> {
+        let color = color.into().to_py(self.wedge.py())?;
+        self.wedge.call_method1("set_facecolor", (color,))?;
+        Ok(self)
+    }
+
+    pub fn set_edge_color(&self, color: impl Into<Color>) -> Result<&Self> {
+        let color = color.into().to_py(self.wedge.py())?;
+        self.wedge.call_method1("set_edgecolor", (color,))?;
+        Ok(self)
+    }
+}
+
+/// The artists drawn by [Axes::pie], one entry per wedge.
+#[derive(Debug)]
+pub struct PieChart<'a> {
+    pub wedges: Vec<Wedge<'a>>,
+    /// The wedge labels, empty without labels.
+    pub labels: Vec<Text<'a>>,
+    /// The percentage labels, empty without [PieOptions::autopct].
+    pub percentages: Vec<Text<'a>>,
+}
+
+impl<'a> Axes<'a> {
+    /// Draw a pie chart of `values`, each wedge spanning its share of the sum,
+    /// optionally labelled by `labels`.
+    /// See `matplotlib.axes.Axes.pie` for more details.
+    pub fn pie(
+        &self,
+        values: &[f64],
+        labels: Option<&[&str]>,
+        options: &PieOptions,
+    ) -> Result<PieChart<'a>> {
+        if values.iter().any(|v| !v.is_finite() || *v < 0.) {
+            return Err(Error::invalid_argument(format!(
+                "invalid pie values {:?}: expected finite non-negative values",
+                values
+            )));
+        }
+        if !values.iter().any(|v| *v > 0.) {
+            return Err(Error::invalid_argument(
+                "invalid pie values: expected a positive sum",
+            ));
+        }
+        let kwargs = options.kwargs(self.py, values.len())?;
+        if let Some(labels) = labels {
+            if labels.len() != values.len() {
+                return Err(Error::invalid_argument(format!(
+                    "expected {} labels for {} wedges, got {}",
+                    values.len(),
+                    values.len(),
+                    labels.len()
+                )));
+            }
+            kwargs.set_item("labels", PyList::new(self.py, labels))?;
+        }
+        let artists =
+            self.axes
+                .call_method("pie", (PyList::new(self.py, values),), Some(kwargs))?;
+        let texts = |index: usize| -> Result<Vec<Text<'a>>> {
+            if index >= artists.len()? {
+                return Ok(vec![]);
+            }
+            artists
+                .get_item(index)?
+                .iter()?
+                .map(|text| Ok(Text { text: text? }))
+                .collect()
+        };
+        let wedges = artists
+            .get_item(0)?
+            .iter()?
+            .map(|wedge| Ok(Wedge { wedge: wedge? }))
+            .collect::<Result<_>>()?;
+        Ok(PieChart {
+            wedges,
+            // Without labels, matplotlib still creates empty label texts.
+            labels: match labels {
+                Some(_) => texts(1)?,
+                None => vec![],
+            },
+            percentages: texts(2)?,
+        })
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{Axes, Error, Figure, FillOptions, LineOptions, Projection, Result};
+use numpy::PyArray1;
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+str_enum! {
+    /// Compass directions, e.g. for [PolarAxes::set_theta_zero_location].
+    pub enum CompassDirection: "compass direction" {
+        N => "N",
+        NE => "NE",
+        E => "E",
+        SE => "SE",
+        S => "S",
+        SW => "SW",
+        W => "W",
+        NW => "NW",
+    }
+}
+
+/// Direction in which theta increases, see [PolarAxes::set_theta_direction].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThetaDirection {
+    /// Mathematically positive, the default.
+    CounterClockwise,
+    /// As on a compass, e.g. for wind roses.
+    Clockwise,
+}
+
+/// Handle to a `matplotlib.projections.polar.PolarAxes`, see [Figure::add_polar_axes].
+///
+/// Angles `theta` are in radians, radii `r` in data units.
+pub struct PolarAxes<'a> {
+    py: Python<'a>,
+    axes: &'a pyo3::types::PyAny,
+}
+
+impl<'a> std::fmt::Debug for PolarAxes<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.axes)
+    }
+}
+
+/// Check that the angles and radii of points have the same length.
+fn check_points(theta: usize, r: usize) -> Result<()> {
+    if theta != r {
+        return Err(Error::invalid_argument(format!(
+            "theta and r must have the same length, got {} and {}",
+            theta, r
+        )));
+    }
+    Ok(())
+}
+
+impl<'a> PolarAxes<'a> {
+    /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
+    pub unsafe fn ax(&self) -> &'a pyo3::types::PyAny {
+        self.axes
+    }
+
+    /// The same axes as [Axes], e.g. for the title or a legend.
+    pub fn as_axes(&self) -> Axes<'a> {
+        Axes {
+            py: self.py,
+            axes: self.axes,
+        }
+    }
+
+    /// Put `theta = 0` at `location`, rotated by `offset` degrees in the direction of theta.
+    pub fn set_theta_zero_location(
+        &self,
+        location: CompassDirection,
+        offset: f64,
+    ) -> Result<&Self> {
+        self.axes
+            .call_method1("set_theta_zero_location", (location.as_str(), offset))?;
+        Ok(self)
+    }
+
+    pub fn set_theta_direction(&self, direction: ThetaDirection) -> Result<&Self> {
+        let direction = match direction {
+            ThetaDirection::CounterClockwise => 1,
+            ThetaDirection::Clockwise => -1,
+        };
+        self.axes
+            .call_method1("set_theta_direction", (direction,))?;
+        Ok(self)
+    }
+
+    /// Set the radial limits, `None` keeps the current value of that end.
+    pub fn set_rlim(&self, bottom: Option<f64>, top: Option<f64>) -> Result<&Self> {
+        let kwargs = PyDict::new(self.py);
+        kwargs.set_item("bottom", bottom)?;
+        kwargs.set_item("top", top)?;
+        self.axes.call_method("set_rlim", (), Some(kwargs))?;
+        Ok(self)
+    }
+
+    /// The radial limits as `(bottom, top)`.
+    pub fn get_rlim(&self) -> Result<(f64, f64)> {
+        Ok(self.axes.call_method0("get_ylim")?.extract()?)
+    }
+
+    /// Set the radii of the circular grid lines.
+    pub fn set_rticks(&self, ticks: &[f64]) -> Result<&Self> {
+        self.axes
+            .call_method1("set_rticks", (PyArray1::from_slice(self.py, ticks),))?;
+        Ok(self)
+    }
+
+    /// Place the labels of the radial ticks along the angle `angle` in degrees.
+    pub fn set_rlabel_position(&self, angle: f64) -> Result<&Self> {
+        self.axes.call_method1("set_rlabel_position", (angle,))?;
+        Ok(self)
+    }
+
+    /// Draw a line through the points `(theta[i], r[i])`.
+    pub fn line<I, J, F, G>(&self, theta: I, r: J, options: &LineOptions) -> Result<&Self>
+    where
+        I: IntoIterator<Item = F>,
+        J: IntoIterator<Item = G>,
+        F: numpy::Element,
+        G: numpy::Element,
+    {
+        let theta: &PyArray1<F> = PyArray1::from_iter(self.py, theta);
+        let r: &PyArray1<G> = PyArray1::from_iter(self.py, r);
+        check_points(theta.len(), r.len())?;
+        self.axes
+            .call_method("plot", (theta, r), Some(options.kwargs(self.py)?))?;
+        Ok(self)
+    }
+
+    /// Draw wedge-shaped bars centered at the angles `theta` extending to the radii `r`,
+    /// each spanning `width` radians, e.g. for wind roses.
+    /// See `matplotlib.axes.Axes.bar` for more details.
+    pub fn bar<I, J, F, G>(
+        &self,
+        theta: I,
+        r: J,
+        width: Option<f64>,
+        options: &FillOptions,
+    ) -> Result<&Self>
+    where
+        I: IntoIterator<Item = F>,
+        J: IntoIterator<Item = G>,
+        F: numpy::Element,
+        G: numpy::Element,
+    {
+        let theta: &PyArray1<F> = PyArray1::from_iter(self.py, theta);
+        let r: &PyArray1<G> = PyArray1::from_iter(self.py, r);
+        check_points(theta.len(), r.len())?;
+        let kwargs = options.kwargs(self.py)?;
+        if let Some(width) = width {
+            kwargs.set_item("width", width)?;
+        }
+        self.axes.call_method("bar", (theta, r), Some(kwargs))?;
+        Ok(self)
+    }
+
+    /// Fill the polygon through the points `(theta[i], r[i])`, e.g. an antenna pattern.
+    /// See `matplotlib.axes.Axes.fill` for more details.
+    pub fn fill<I, J, F, G>(&self, theta: I, r: J, options: &FillOptions) -> Result<&Self>
+    where
+        I: IntoIterator<Item = F>,
+        J: IntoIterator<Item = G>,
+        F: numpy::Element,
+        G: numpy::Element,
+    {
+        let theta: &PyArray1<F> = PyArray1::from_iter(self.py, theta);
+        let r: &PyArray1<G> = PyArray1::from_iter(self.py, r);
+        check_points(theta.len(), r.len())?;
+        self.axes
+            .call_method("fill", (theta, r), Some(options.kwargs(self.py)?))?;
+        Ok(self)
+    }
+}
+
+impl<'a> Axes<'a> {
+    /// These axes as [PolarAxes], if they were created with [Projection::Polar].
+    pub fn as_polar(&self) -> Result<PolarAxes<'a>> {
+        let name: String = self.axes.getattr("name")?.extract()?;
+        if name != Projection::Polar.as_str() {
+            return Err(Error::invalid_argument(format!(
+                "expected polar axes, got {} axes",
+                name
+            )));
+        }
+        Ok(PolarAxes {
+            py: self.py,
+            axes: self.axes,
+        })
+    }
+}
+
+impl<'a> Figure<'a> {
+    /// Add polar axes filling the figure.
+    pub fn add_polar_axes(&self) -> Result<PolarAxes<'a>> {
+        let kwargs = PyDict::new(self.py);
+        kwargs.set_item("projection", Projection::Polar)?;
+        let axes = self.fig.call_method("add_subplot", (), Some(kwargs))?;
+        Ok(PolarAxes { py: self.py, axes })
+    }
+}
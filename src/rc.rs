@@ -0,0 +1,145 @@
+use crate::{Color, Error, PyPlot, Result};
+use pyo3::types::{PyDict, PyList};
+use pyo3::{FromPyObject, Python, ToPyObject};
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+/// Handle to the global `matplotlib.rcParams`, see [PyPlot::rc_params].
+///
+/// Typed accessors cover common keys, [RcParams::get] and [RcParams::set] any other key.
+/// Unknown keys and invalid values are rejected with [Error::InvalidArgument].
+pub struct RcParams<'a> {
+    py: Python<'a>,
+    params: &'a pyo3::types::PyAny,
+}
+
+impl<'a> RcParams<'a> {
+    pub fn get<T: FromPyObject<'a>>(&self, key: &str) -> Result<T> {
+        Ok(self.params.get_item(key)?.extract()?)
+    }
+
+    pub fn set<V: ToPyObject>(&self, key: &str, value: V) -> Result<&Self> {
+        self.params.set_item(key, value)?;
+        Ok(self)
+    }
+
+    /// Default font size in points (`font.size`).
+    pub fn font_size(&self) -> Result<f64> {
+        self.get("font.size")
+    }
+
+    pub fn set_font_size(&self, size: f64) -> Result<&Self> {
+        self.set("font.size", size)
+    }
+
+    /// Resolution of new figures in dots per inch (`figure.dpi`).
+    pub fn figure_dpi(&self) -> Result<f64> {
+        self.get("figure.dpi")
+    }
+
+    pub fn set_figure_dpi(&self, dpi: f64) -> Result<&Self> {
+        self.set("figure.dpi", dpi)
+    }
+
+    /// Default line width in points (`lines.linewidth`).
+    pub fn line_width(&self) -> Result<f64> {
+        self.get("lines.linewidth")
+    }
+
+    pub fn set_line_width(&self, width: f64) -> Result<&Self> {
+        self.set("lines.linewidth", width)
+    }
+
+    /// Colors of the property cycle (`axes.prop_cycle`) as RGBA.
+    pub fn color_cycle(&self) -> Result<Vec<Color>> {
+        let to_rgba = self.py.import("matplotlib.colors")?.getattr("to_rgba")?;
+        self.params
+            .get_item("axes.prop_cycle")?
+            .call_method0("by_key")?
+            .get_item("color")?
+            .iter()?
+            .map(|color| {
+                let (r, g, b, a) = to_rgba.call1((color?,))?.extract()?;
+                Ok(Color::Rgba(r, g, b, a))
+            })
+            .collect()
+    }
+
+    /// Replace the property cycle (`axes.prop_cycle`) by a cycle over `colors`.
+    pub fn set_color_cycle(&self, colors: &[Color]) -> Result<&Self> {
+        if colors.is_empty() {
+            return Err(Error::invalid_argument("empty color cycle"));
+        }
+        let colors = colors
+            .iter()
+            .map(|c| c.to_py(self.py))
+            .collect::<Result<Vec<_>>>()?;
+        let kwargs = PyDict::new(self.py);
+        kwargs.set_item("color", PyList::new(self.py, colors))?;
+        let cycle = self
+            .py
+            .import("cycler")?
+            .call_method("cycler", (), Some(kwargs))?;
+        self.set("axes.prop_cycle", cycle)
+    }
+}
+
+impl<'a> PyPlot<'a> {
+    /// The global default settings.
+    pub fn rc_params(&self) -> Result<RcParams<'a>> {
+        let params = self.py.import("matplotlib")?.getattr("rcParams")?;
+        Ok(RcParams {
+            py: self.py,
+            params,
+        })
+    }
+
+    /// Run `f` within the context manager `ctx`, exiting it also when `f` fails or panics.
+    fn within<R, E, F>(&self, ctx: &'a pyo3::types::PyAny, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&PyPlot<'a>) -> Result<R, E>,
+        E: From<Error>,
+    {
+        ctx.call_method0("__enter__").map_err(Error::from)?;
+        let result = catch_unwind(AssertUnwindSafe(|| f(self)));
+        let exit = ctx.call_method1("__exit__", (None::<()>, None::<()>, None::<()>));
+        let value = result.unwrap_or_else(|payload| resume_unwind(payload))?;
+        exit.map_err(Error::from)?;
+        Ok(value)
+    }
+
+    /// Run `f` with the style sheets `styles` applied, e.g. `["ggplot"]`.
+    /// Settings are restored afterwards, also when `f` fails or panics.
+    /// See `matplotlib.style.context` for more details.
+    pub fn with_style<R, E, F>(&self, styles: &[&str], f: F) -> Result<R, E>
+    where
+        F: FnOnce(&PyPlot<'a>) -> Result<R, E>,
+        E: From<Error>,
+    {
+        let ctx = self
+            .plt
+            .getattr("style")
+            .and_then(|style| style.call_method1("context", (PyList::new(self.py, styles),)))
+            .map_err(Error::from)?;
+        self.within(ctx, f)
+    }
+
+    /// Run `f` with the `rcParams` overrides `rc` applied.
+    /// Settings are restored afterwards, also when `f` fails or panics.
+    /// See `matplotlib.rc_context` for more details.
+    pub fn with_rc<R, E, F>(&self, rc: &[(&str, &dyn ToPyObject)], f: F) -> Result<R, E>
+    where
+        F: FnOnce(&PyPlot<'a>) -> Result<R, E>,
+        E: From<Error>,
+    {
+        let params = PyDict::new(self.py);
+        for (key, value) in rc {
+            params.set_item(key, value).map_err(Error::from)?;
+        }
+        let ctx = self
+            .py
+            .import("matplotlib")
+            .and_then(|mpl| mpl.call_method1("rc_context", (params,)))
+            .map_err(Error::from)?;
+        self.within(ctx, f)
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{Color, Figure, PyPlot, Result};
+use pyo3::types::{IntoPyDict, PyBytes, PyDict, PyString};
+use pyo3::Python;
+use std::path::Path;
+
+str_enum! {
+    /// Output formats of [Figure::to_bytes] and [Figure::savefig].
+    pub enum Format: "format" {
+        Png => "png",
+        Svg => "svg",
+        Pdf => "pdf",
+        Eps => "eps",
+        Ps => "ps",
+    }
+}
+
+impl Format {
+    /// Infer the format from the extension of `path`, ignoring case.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        extension.parse().ok()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BboxInches {
+    Tight,
+    Bounds(f64, f64, f64, f64),
+}
+
+/// Options of [Figure::savefig] and [Figure::to_bytes].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SaveOptions {
+    format: Option<Format>,
+    dpi: Option<f64>,
+    transparent: Option<bool>,
+    face_color: Option<Color>,
+    bbox_inches: Option<BboxInches>,
+    pad_inches: Option<f64>,
+    metadata: Vec<(String, String)>,
+}
+
+impl SaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Format used by [Figure::savefig], inferred from the file extension by default.
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Resolution in dots per inch, the figure's dpi by default.
+    pub fn dpi(mut self, dpi: f64) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Make the figure and axes backgrounds transparent.
+    pub fn transparent(mut self, transparent: bool) -> Self {
+        self.transparent = Some(transparent);
+        self
+    }
+
+    /// Background color of the figure, its current face color by default.
+    pub fn face_color(mut self, color: impl Into<Color>) -> Self {
+        self.face_color = Some(color.into());
+        self
+    }
+
+    /// Crop the output to the drawn content (`bbox_inches="tight"`).
+    pub fn tight(mut self, tight: bool) -> Self {
+        self.bbox_inches = tight.then_some(BboxInches::Tight);
+        self
+    }
+
+    /// Save only the part of the figure within the given bounds in inches.
+    pub fn bbox_inches(mut self, left: f64, bottom: f64, width: f64, height: f64) -> Self {
+        self.bbox_inches = Some(BboxInches::Bounds(left, bottom, width, height));
+        self
+    }
+
+    /// Padding in inches around the content when [SaveOptions::tight] is set.
+    pub fn pad_inches(mut self, pad: f64) -> Self {
+        self.pad_inches = Some(pad);
+        self
+    }
+
+    /// Add a metadata entry such as `Title` or `Author`,
+    /// the supported keys depend on the format.
+    pub fn metadata(mut self, key: &str, value: &str) -> Self {
+        self.metadata.push((key.to_owned(), value.to_owned()));
+        self
+    }
+
+    pub(crate) fn kwargs<'py>(&self, py: Python<'py>) -> Result<&'py PyDict> {
+        let kwargs = PyDict::new(py);
+        if let Some(dpi) = self.dpi {
+            kwargs.set_item("dpi", dpi)?;
+        }
+        if let Some(transparent) = self.transparent {
+            kwargs.set_item("transparent", transparent)?;
+        }
+        if let Some(format) = self.format {
+            kwargs.set_item("format", format)?;
+        }
+        if let Some(color) = &self.face_color {
+            kwargs.set_item("facecolor", color.to_py(py)?)?;
+        }
+        match self.bbox_inches {
+            Some(BboxInches::Tight) => kwargs.set_item("bbox_inches", "tight")?,
+            Some(BboxInches::Bounds(left, bottom, width, height)) => {
+                let bbox = py
+                    .import("matplotlib.transforms")?
+                    .getattr("Bbox")?
+                    .call_method1("from_bounds", (left, bottom, width, height))?;
+                kwargs.set_item("bbox_inches", bbox)?;
+            }
+            None => {}
+        }
+        if let Some(pad) = self.pad_inches {
+            kwargs.set_item("pad_inches", pad)?;
+        }
+        if !self.metadata.is_empty() {
+            kwargs.set_item("metadata", self.metadata.clone().into_py_dict(py))?;
+        }
+        Ok(kwargs)
+    }
+}
+
+/// Convert `path` to a Python `str`, decoding paths that are not valid UTF-8
+/// with the file system encoding like Python itself does (`os.fsdecode`).
+fn path_to_py<'py>(py: Python<'py>, path: &Path) -> Result<&'py pyo3::types::PyAny> {
+    if let Some(path) = path.to_str() {
+        return Ok(PyString::new(py, path));
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        let bytes = PyBytes::new(py, path.as_os_str().as_bytes());
+        Ok(py.import("os")?.call_method1("fsdecode", (bytes,))?)
+    }
+    #[cfg(not(unix))]
+    Err(crate::Error::invalid_argument(format!(
+        "Invalid path: {:?}",
+        path
+    )))
+}
+
+impl<'a> Figure<'a> {
+    /// Save the figure to `path`.
+    /// See `matplotlib.figure.Figure.savefig` for more details.
+    pub fn savefig<P: AsRef<Path>>(&self, path: P, options: &SaveOptions) -> Result<()> {
+        let path = path.as_ref();
+        let kwargs = options.kwargs(self.py)?;
+        if options.format.is_none() {
+            if let Some(format) = Format::from_path(path) {
+                kwargs.set_item("format", format)?;
+            }
+        }
+        self.fig
+            .call_method("savefig", (path_to_py(self.py, path)?,), Some(kwargs))?;
+        Ok(())
+    }
+
+    /// Render the figure in `format` into memory instead of a file,
+    /// overriding [SaveOptions::format].
+    /// See `matplotlib.figure.Figure.savefig` for more details.
+    pub fn to_bytes(&self, format: Format, options: &SaveOptions) -> Result<Vec<u8>> {
+        let buffer = self.py.import("io")?.call_method0("BytesIO")?;
+        let kwargs = options.kwargs(self.py)?;
+        kwargs.set_item("format", format)?;
+        self.fig.call_method("savefig", (buffer,), Some(kwargs))?;
+        let bytes: &PyBytes = buffer
+            .call_method0("getvalue")?
+            .downcast()
+            .map_err(pyo3::PyErr::from)?;
+        Ok(bytes.as_bytes().to_vec())
+    }
+}
+
+impl<'a> PyPlot<'a> {
+    /// Save the current figure to `path`, see [Figure::savefig].
+    pub fn savefig<P: AsRef<Path>>(&self, path: P, options: &SaveOptions) -> Result<()> {
+        self.gcf()?.savefig(path, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Format;
+
+    #[test]
+    fn format_from_path() {
+        assert_eq!(Format::from_path("a.PNG"), Some(Format::Png));
+        assert_eq!(Format::from_path("a.svg"), Some(Format::Svg));
+        assert_eq!(Format::from_path("dir.pdf/a.eps"), Some(Format::Eps));
+        assert_eq!(Format::from_path("a.jpg"), None);
+        assert_eq!(Format::from_path("a"), None);
+        assert_eq!(Format::from_path(".png"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn format_from_non_utf8_path() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = OsStr::from_bytes(b"plot.\xff\xfe");
+        assert_eq!(Format::from_path(path), None);
+    }
+}
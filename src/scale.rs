@@ -0,0 +1,148 @@
+use crate::{Axes, Error, Result};
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+/// Axis scales, see `matplotlib.scale`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scale {
+    Linear,
+    /// Logarithmic scale, only positive values are shown.
+    Log {
+        base: f64,
+    },
+    /// Symmetric logarithmic scale, linear within `(-linthresh, linthresh)`.
+    SymLog {
+        linthresh: f64,
+        base: f64,
+    },
+    /// Logistic scale for values in `(0, 1)`, e.g. probabilities.
+    Logit,
+}
+
+impl Scale {
+    fn args<'py>(&self, py: Python<'py>) -> Result<(&'static str, &'py PyDict)> {
+        let kwargs = PyDict::new(py);
+        let name = match *self {
+            Scale::Linear => "linear",
+            Scale::Log { base } => {
+                if base <= 1. {
+                    return Err(Error::invalid_argument(format!(
+                        "invalid log scale base {}: expected > 1",
+                        base
+                    )));
+                }
+                kwargs.set_item("base", base)?;
+                "log"
+            }
+            Scale::SymLog { linthresh, base } => {
+                if linthresh <= 0. {
+                    return Err(Error::invalid_argument(format!(
+                        "invalid symlog threshold {}: expected > 0",
+                        linthresh
+                    )));
+                }
+                if base <= 1. {
+                    return Err(Error::invalid_argument(format!(
+                        "invalid symlog scale base {}: expected > 1",
+                        base
+                    )));
+                }
+                kwargs.set_item("linthresh", linthresh)?;
+                kwargs.set_item("base", base)?;
+                "symlog"
+            }
+            Scale::Logit => "logit",
+        };
+        Ok((name, kwargs))
+    }
+}
+
+/// Aspect ratio of the data units, see [Axes::set_aspect].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Aspect {
+    /// Fill the available space.
+    Auto,
+    /// One unit in x is as long as one unit in y.
+    Equal,
+    /// One unit in y is this many times as long as one unit in x.
+    Ratio(f64),
+}
+
+impl<'a> Axes<'a> {
+    /// Set the x limits, `None` keeps the current value of that end.
+    pub fn set_xlim(&self, left: Option<f64>, right: Option<f64>) -> Result<&Self> {
+        let kwargs = PyDict::new(self.py);
+        kwargs.set_item("left", left)?;
+        kwargs.set_item("right", right)?;
+        self.axes.call_method("set_xlim", (), Some(kwargs))?;
+        Ok(self)
+    }
+
+    /// Set the y limits, `None` keeps the current value of that end.
+    pub fn set_ylim(&self, bottom: Option<f64>, top: Option<f64>) -> Result<&Self> {
+        let kwargs = PyDict::new(self.py);
+        kwargs.set_item("bottom", bottom)?;
+        kwargs.set_item("top", top)?;
+        self.axes.call_method("set_ylim", (), Some(kwargs))?;
+        Ok(self)
+    }
+
+    /// The x limits as `(left, right)`.
+    pub fn get_xlim(&self) -> Result<(f64, f64)> {
+        Ok(self.axes.call_method0("get_xlim")?.extract()?)
+    }
+
+    /// The y limits as `(bottom, top)`.
+    pub fn get_ylim(&self) -> Result<(f64, f64)> {
+        Ok(self.axes.call_method0("get_ylim")?.extract()?)
+    }
+
+    pub fn set_xscale(&self, scale: Scale) -> Result<&Self> {
+        let (name, kwargs) = scale.args(self.py)?;
+        self.axes.call_method("set_xscale", (name,), Some(kwargs))?;
+        Ok(self)
+    }
+
+    pub fn set_yscale(&self, scale: Scale) -> Result<&Self> {
+        let (name, kwargs) = scale.args(self.py)?;
+        self.axes.call_method("set_yscale", (name,), Some(kwargs))?;
+        Ok(self)
+    }
+
+    /// Flip the direction of the x axis.
+    pub fn invert_xaxis(&self) -> Result<&Self> {
+        self.axes.call_method0("invert_xaxis")?;
+        Ok(self)
+    }
+
+    /// Flip the direction of the y axis.
+    pub fn invert_yaxis(&self) -> Result<&Self> {
+        self.axes.call_method0("invert_yaxis")?;
+        Ok(self)
+    }
+
+    pub fn xaxis_inverted(&self) -> Result<bool> {
+        Ok(self.axes.call_method0("xaxis_inverted")?.extract()?)
+    }
+
+    pub fn yaxis_inverted(&self) -> Result<bool> {
+        Ok(self.axes.call_method0("yaxis_inverted")?.extract()?)
+    }
+
+    pub fn set_aspect(&self, aspect: Aspect) -> Result<&Self> {
+        match aspect {
+            Aspect::Auto => self.axes.call_method1("set_aspect", ("auto",))?,
+            Aspect::Equal => self.axes.call_method1("set_aspect", ("equal",))?,
+            Aspect::Ratio(ratio) => {
+                if !(ratio.is_finite() && ratio > 0.) {
+                    return Err(Error::invalid_argument(format!(
+                        "invalid aspect ratio {}: expected > 0",
+                        ratio
+                    )));
+                }
+                self.axes.call_method1("set_aspect", (ratio,))?
+            }
+        };
+        Ok(self)
+    }
+}
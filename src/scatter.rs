@@ -0,0 +1,222 @@
+use crate::{check_alpha, Axes, Color, Error, Marker, Result};
+use numpy::PyArray1;
+use pyo3::types::{PyDict, PyList};
+use pyo3::Python;
+
+#[derive(Debug, Clone, PartialEq)]
+enum PerPoint<T> {
+    All(T),
+    Each(Vec<T>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ScatterColor {
+    Fixed(PerPoint<Color>),
+    Mapped(Vec<f64>),
+}
+
+/// Styling of the markers drawn by [Axes::scatter_with].
+///
+/// Sizes and colors are either shared by all points or given per point, in which case
+/// their length must match the number of points.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScatterOptions {
+    sizes: Option<PerPoint<f64>>,
+    color: Option<ScatterColor>,
+    cmap: Option<String>,
+    vmin: Option<f64>,
+    vmax: Option<f64>,
+    edge_colors: Option<PerPoint<Color>>,
+    edge_width: Option<f64>,
+    marker: Option<Marker>,
+    alpha: Option<f64>,
+    label: Option<String>,
+    zorder: Option<f64>,
+}
+
+impl ScatterOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marker size of all points in points².
+    pub fn size(mut self, size: f64) -> Self {
+        self.sizes = Some(PerPoint::All(size));
+        self
+    }
+
+    /// Marker size of each point in points².
+    pub fn sizes(mut self, sizes: &[f64]) -> Self {
+        self.sizes = Some(PerPoint::Each(sizes.to_vec()));
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(ScatterColor::Fixed(PerPoint::All(color.into())));
+        self
+    }
+
+    pub fn colors(mut self, colors: &[Color]) -> Self {
+        self.color = Some(ScatterColor::Fixed(PerPoint::Each(colors.to_vec())));
+        self
+    }
+
+    /// Color each point by mapping `values` through the colormap, see [ScatterOptions::cmap].
+    pub fn values(mut self, values: &[f64]) -> Self {
+        self.color = Some(ScatterColor::Mapped(values.to_vec()));
+        self
+    }
+
+    /// Name of the colormap used for [ScatterOptions::values], e.g. `"viridis"`.
+    pub fn cmap(mut self, cmap: &str) -> Self {
+        self.cmap = Some(cmap.to_owned());
+        self
+    }
+
+    /// Range of [ScatterOptions::values] covered by the colormap,
+    /// open ends default to the data range.
+    pub fn range(mut self, vmin: Option<f64>, vmax: Option<f64>) -> Self {
+        self.vmin = vmin;
+        self.vmax = vmax;
+        self
+    }
+
+    pub fn edge_color(mut self, color: impl Into<Color>) -> Self {
+        self.edge_colors = Some(PerPoint::All(color.into()));
+        self
+    }
+
+    pub fn edge_colors(mut self, colors: &[Color]) -> Self {
+        self.edge_colors = Some(PerPoint::Each(colors.to_vec()));
+        self
+    }
+
+    /// Width of the marker edges in points.
+    pub fn edge_width(mut self, width: f64) -> Self {
+        self.edge_width = Some(width);
+        self
+    }
+
+    pub fn marker(mut self, marker: Marker) -> Self {
+        self.marker = Some(marker);
+        self
+    }
+
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_owned());
+        self
+    }
+
+    pub fn zorder(mut self, zorder: f64) -> Self {
+        self.zorder = Some(zorder);
+        self
+    }
+
+    /// Convert to the keyword arguments of `matplotlib.axes.Axes.scatter` for `n` points.
+    pub(crate) fn kwargs<'py>(&self, py: Python<'py>, n: usize) -> Result<&'py PyDict> {
+        let check_len = |what: &str, len: usize| {
+            if len == n {
+                Ok(())
+            } else {
+                Err(Error::invalid_argument(format!(
+                    "expected {} {} for {} points, got {}",
+                    n, what, n, len
+                )))
+            }
+        };
+        let colors = |colors: &[Color]| -> Result<&PyList> {
+            let colors = colors
+                .iter()
+                .map(|c| c.to_py(py))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(PyList::new(py, colors))
+        };
+        let kwargs = PyDict::new(py);
+        match &self.sizes {
+            Some(PerPoint::All(size)) => kwargs.set_item("s", size)?,
+            Some(PerPoint::Each(sizes)) => {
+                check_len("sizes", sizes.len())?;
+                kwargs.set_item("s", PyArray1::from_slice(py, sizes))?;
+            }
+            None => {}
+        }
+        match &self.color {
+            Some(ScatterColor::Fixed(PerPoint::All(color))) => {
+                kwargs.set_item("color", color.to_py(py)?)?
+            }
+            Some(ScatterColor::Fixed(PerPoint::Each(each))) => {
+                check_len("colors", each.len())?;
+                kwargs.set_item("c", colors(each)?)?;
+            }
+            Some(ScatterColor::Mapped(values)) => {
+                check_len("values", values.len())?;
+                kwargs.set_item("c", PyArray1::from_slice(py, values))?;
+            }
+            None => {}
+        }
+        if let Some(cmap) = &self.cmap {
+            kwargs.set_item("cmap", cmap)?;
+        }
+        if let Some(vmin) = self.vmin {
+            kwargs.set_item("vmin", vmin)?;
+        }
+        if let Some(vmax) = self.vmax {
+            kwargs.set_item("vmax", vmax)?;
+        }
+        match &self.edge_colors {
+            Some(PerPoint::All(color)) => kwargs.set_item("edgecolors", color.to_py(py)?)?,
+            Some(PerPoint::Each(each)) => {
+                check_len("edge colors", each.len())?;
+                kwargs.set_item("edgecolors", colors(each)?)?;
+            }
+            None => {}
+        }
+        if let Some(width) = self.edge_width {
+            kwargs.set_item("linewidths", width)?;
+        }
+        if let Some(marker) = self.marker {
+            kwargs.set_item("marker", marker)?;
+        }
+        if let Some(alpha) = self.alpha {
+            check_alpha(alpha)?;
+            kwargs.set_item("alpha", alpha)?;
+        }
+        if let Some(label) = &self.label {
+            kwargs.set_item("label", label)?;
+        }
+        if let Some(zorder) = self.zorder {
+            kwargs.set_item("zorder", zorder)?;
+        }
+        Ok(kwargs)
+    }
+}
+
+impl<'a> Axes<'a> {
+    /// Draw a marker at each point with per-point sizes and colors.
+    /// See `matplotlib.axes.Axes.scatter` for more details.
+    pub fn scatter_with<I, J, F, G>(&self, x: I, y: J, options: &ScatterOptions) -> Result<&Self>
+    where
+        I: IntoIterator<Item = F>,
+        J: IntoIterator<Item = G>,
+        F: numpy::Element,
+        G: numpy::Element,
+    {
+        let x: &PyArray1<F> = PyArray1::from_iter(self.py, x);
+        let y: &PyArray1<G> = PyArray1::from_iter(self.py, y);
+        if x.len() != y.len() {
+            return Err(Error::invalid_argument(format!(
+                "x and y must have the same length, got {} and {}",
+                x.len(),
+                y.len()
+            )));
+        }
+        let kwargs = options.kwargs(self.py, x.len())?;
+        self.axes.call_method("scatter", (x, y), Some(kwargs))?;
+        Ok(self)
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{check_alpha, Color, Error, Result};
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+str_enum! {
+    /// Named line styles, see `matplotlib.lines.Line2D.set_linestyle`.
+    pub enum LineStyle: "line style" {
+        Solid => "-",
+        Dashed => "--",
+        DashDot => "-.",
+        Dotted => ":",
+        /// Draw no line at all, e.g. to show only markers.
+        None => "None",
+    }
+}
+
+str_enum! {
+    /// Marker symbols, see `matplotlib.markers`.
+    pub enum Marker: "marker" {
+        Point => ".",
+        Pixel => ",",
+        Circle => "o",
+        TriangleDown => "v",
+        TriangleUp => "^",
+        TriangleLeft => "<",
+        TriangleRight => ">",
+        Octagon => "8",
+        Square => "s",
+        Pentagon => "p",
+        PlusFilled => "P",
+        Star => "*",
+        Hexagon => "h",
+        Plus => "+",
+        X => "x",
+        XFilled => "X",
+        Diamond => "D",
+        ThinDiamond => "d",
+        VLine => "|",
+        HLine => "_",
+        None => "None",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Dash {
+    Named(LineStyle),
+    Pattern(f64, Vec<f64>),
+}
+
+/// Styling of a line drawn by [crate::Axes::line].
+///
+/// Unset options fall back to the matplotlib defaults (`rcParams`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LineOptions {
+    color: Option<Color>,
+    width: Option<f64>,
+    dash: Option<Dash>,
+    marker: Option<Marker>,
+    marker_size: Option<f64>,
+    alpha: Option<f64>,
+    label: Option<String>,
+    zorder: Option<f64>,
+}
+
+impl LineOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Line width in points.
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.dash = Some(Dash::Named(style));
+        self
+    }
+
+    /// Custom dash pattern of alternating on/off lengths in points, starting at `offset`.
+    /// Replaces any previously set [LineOptions::style].
+    pub fn dashes(mut self, offset: f64, pattern: &[f64]) -> Self {
+        self.dash = Some(Dash::Pattern(offset, pattern.to_vec()));
+        self
+    }
+
+    pub fn marker(mut self, marker: Marker) -> Self {
+        self.marker = Some(marker);
+        self
+    }
+
+    /// Marker size in points.
+    pub fn marker_size(mut self, size: f64) -> Self {
+        self.marker_size = Some(size);
+        self
+    }
+
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_owned());
+        self
+    }
+
+    pub fn zorder(mut self, zorder: f64) -> Self {
+        self.zorder = Some(zorder);
+        self
+    }
+
+    /// Convert to the keyword arguments of `matplotlib.axes.Axes.plot`.
+    pub(crate) fn kwargs<'py>(&self, py: Python<'py>) -> Result<&'py PyDict> {
+        let kwargs = PyDict::new(py);
+        if let Some(color) = &self.color {
+            kwargs.set_item("color", color.to_py(py)?)?;
+        }
+        if let Some(width) = self.width {
+            kwargs.set_item("linewidth", width)?;
+        }
+        match &self.dash {
+            Some(Dash::Named(style)) => kwargs.set_item("linestyle", style)?,
+            Some(Dash::Pattern(offset, pattern)) => {
+                if pattern.is_empty()
+                    || pattern.len() % 2 != 0
+                    || pattern.iter().any(|v| !v.is_finite() || *v < 0.)
+                    || pattern.iter().all(|v| *v == 0.)
+                {
+                    return Err(Error::invalid_argument(format!(
+                        "invalid dash pattern {:?}: expected an even number of non-negative on/off lengths",
+                        pattern
+                    )));
+                }
+                let pattern = pyo3::types::PyTuple::new(py, pattern);
+                kwargs.set_item("linestyle", (*offset, pattern))?;
+            }
+            None => {}
+        }
+        if let Some(marker) = self.marker {
+            kwargs.set_item("marker", marker)?;
+        }
+        if let Some(size) = self.marker_size {
+            kwargs.set_item("markersize", size)?;
+        }
+        if let Some(alpha) = self.alpha {
+            check_alpha(alpha)?;
+            kwargs.set_item("alpha", alpha)?;
+        }
+        if let Some(label) = &self.label {
+            kwargs.set_item("label", label)?;
+        }
+        if let Some(zorder) = self.zorder {
+            kwargs.set_item("zorder", zorder)?;
+        }
+        Ok(kwargs)
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{Color, Result};
+
+str_enum! {
+    /// Font weights, ordered from thin to thick.
+    pub enum FontWeight: "font weight" {
+        UltraLight => "ultralight",
+        Light => "light",
+        Normal => "normal",
+        Medium => "medium",
+        SemiBold => "semibold",
+        Bold => "bold",
+        Heavy => "heavy",
+        Black => "black",
+    }
+}
+
+impl FontWeight {
+    /// The numeric weight in `100..=900` as used by matplotlib's font manager.
+    pub fn numeric(&self) -> u32 {
+        match self {
+            FontWeight::UltraLight => 100,
+            FontWeight::Light => 200,
+            FontWeight::Normal => 400,
+            FontWeight::Medium => 500,
+            FontWeight::SemiBold => 600,
+            FontWeight::Bold => 700,
+            FontWeight::Heavy => 800,
+            FontWeight::Black => 900,
+        }
+    }
+
+    /// The closest named weight to a numeric weight.
+    fn from_numeric(weight: u32) -> Self {
+        use FontWeight::*;
+        [
+            UltraLight, Light, Normal, Medium, SemiBold, Bold, Heavy, Black,
+        ]
+        .into_iter()
+        .min_by_key(|w| (w.numeric() as i64 - weight as i64).abs())
+        .unwrap()
+    }
+
+    /// Parse the weight as returned by `Text.get_fontweight`, which also includes the
+    /// aliases `regular`, `book`, `roman`, `demibold`, `demi` and `extra bold`.
+    fn from_py(weight: &pyo3::types::PyAny) -> Result<Self> {
+        if let Ok(weight) = weight.extract::<u32>() {
+            return Ok(Self::from_numeric(weight));
+        }
+        let weight: String = weight.extract()?;
+        Ok(match weight.as_str() {
+            "regular" | "book" => FontWeight::Normal,
+            "roman" => FontWeight::Medium,
+            "demibold" | "demi" => FontWeight::SemiBold,
+            "extra bold" => FontWeight::Heavy,
+            _ => weight.parse()?,
+        })
+    }
+}
+
+str_enum! {
+    pub enum FontStyle: "font style" {
+        Normal => "normal",
+        Italic => "italic",
+        Oblique => "oblique",
+    }
+}
+
+str_enum! {
+    pub enum HorizontalAlignment: "horizontal alignment" {
+        Left => "left",
+        Center => "center",
+        Right => "right",
+    }
+}
+
+str_enum! {
+    pub enum VerticalAlignment: "vertical alignment" {
+        Top => "top",
+        Center => "center",
+        Bottom => "bottom",
+        Baseline => "baseline",
+        CenterBaseline => "center_baseline",
+    }
+}
+
+/// Handle to a `matplotlib.text.Text`, e.g. a title or axis label.
+pub struct Text<'a> {
+    pub(crate) text: &'a pyo3::types::PyAny,
+}
+
+impl<'a> std::fmt::Debug for Text<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.text)
+    }
+}
+
+impl<'a> Text<'a> {
+    /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
+    pub unsafe fn text(&self) -> &'a pyo3::types::PyAny {
+        self.text
+    }
+
+    fn get<T: pyo3::FromPyObject<'a>>(&self, getter: &str) -> Result<T> {
+        Ok(self.text.call_method0(getter)?.extract()?)
+    }
+
+    fn set<V: pyo3::IntoPy<pyo3::Py<pyo3::types::PyTuple>>>(
+        &self,
+        setter: &str,
+        value: V,
+    ) -> Result<&Self> {
+        self.text.call_method1(setter, value)?;
+        Ok(self)
+    }
+
+    pub fn get_text(&self) -> Result<String> {
+        self.get("get_text")
+    }
+
+    pub fn set_text(&self, text: &str) -> Result<&Self> {
+        self.set("set_text", (text,))
+    }
+
+    /// Font size in points.
+    pub fn get_fontsize(&self) -> Result<f64> {
+        self.get("get_fontsize")
+    }
+
+    /// Set the font size in points.
+    pub fn set_fontsize(&self, size: f64) -> Result<&Self> {
+        self.set("set_fontsize", (size,))
+    }
+
+    pub fn get_fontweight(&self) -> Result<FontWeight> {
+        FontWeight::from_py(self.text.call_method0("get_fontweight")?)
+    }
+
+    pub fn set_fontweight(&self, weight: FontWeight) -> Result<&Self> {
+        self.set("set_fontweight", (weight.as_str(),))
+    }
+
+    /// Font family names in order of preference.
+    pub fn get_fontfamily(&self) -> Result<Vec<String>> {
+        self.get("get_fontfamily")
+    }
+
+    /// Set the font family, either a font name or one of the generic families
+    /// `serif`, `sans-serif`, `cursive`, `fantasy` and `monospace`.
+    pub fn set_fontfamily(&self, family: &str) -> Result<&Self> {
+        self.set("set_fontfamily", (family,))
+    }
+
+    pub fn get_fontstyle(&self) -> Result<FontStyle> {
+        let style: String = self.get("get_fontstyle")?;
+        Ok(style.parse()?)
+    }
+
+    pub fn set_fontstyle(&self, style: FontStyle) -> Result<&Self> {
+        self.set("set_fontstyle", (style.as_str(),))
+    }
+
+    /// The text color as RGBA.
+    pub fn get_color(&self) -> Result<Color> {
+        let color = self.text.call_method0("get_color")?;
+        let (r, g, b, a) = self
+            .text
+            .py()
+            .import("matplotlib.colors")?
+            .call_method1("to_rgba", (color,))?
+            .extract()?;
+        Ok(Color::Rgba(r, g, b, a))
+    }
+
+    pub fn set_color(&self, color: impl Into<Color>) -> Result<&Self> {
+        let color = color.into().to_py(self.text.py())?;
+        self.set("set_color", (color,))
+    }
+
+    /// Rotation in degrees, counter-clockwise.
+    pub fn get_rotation(&self) -> Result<f64> {
+        self.get("get_rotation")
+    }
+
+    /// Set the rotation in degrees, counter-clockwise.
+    pub fn set_rotation(&self, degrees: f64) -> Result<&Self> {
+        self.set("set_rotation", (degrees,))
+    }
+
+    pub fn get_horizontalalignment(&self) -> Result<HorizontalAlignment> {
+        let align: String = self.get("get_horizontalalignment")?;
+        Ok(align.parse()?)
+    }
+
+    pub fn set_horizontalalignment(&self, align: HorizontalAlignment) -> Result<&Self> {
+        self.set("set_horizontalalignment", (align.as_str(),))
+    }
+
+    pub fn get_verticalalignment(&self) -> Result<VerticalAlignment> {
+        let align: String = self.get("get_verticalalignment")?;
+        Ok(align.parse()?)
+    }
+
+    pub fn set_verticalalignment(&self, align: VerticalAlignment) -> Result<&Self> {
+        self.set("set_verticalalignment", (align.as_str(),))
+    }
+
+    pub fn get_visible(&self) -> Result<bool> {
+        self.get("get_visible")
+    }
+
+    pub fn set_visible(&self, visible: bool) -> Result<&Self> {
+        self.set("set_visible", (visible,))
+    }
+}
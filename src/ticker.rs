@@ -0,0 +1,80 @@
+use crate::callback::TickFormatter;
+use crate::{Axes, Result};
+use pyo3::Py;
+
+/// Handle to the x or y `matplotlib.axis.Axis` of an [Axes], see [Axes::xaxis].
+pub struct Axis<'a> {
+    axis: &'a pyo3::types::PyAny,
+}
+
+impl<'a> Axis<'a> {
+    /// Provide Python handle
+    ///
+    /// # Safety
+    /// Calls made through the raw handle bypass the typed wrappers of this crate.
+    pub unsafe fn axis(&self) -> &'a pyo3::types::PyAny {
+        self.axis
+    }
+
+    fn func_formatter(&self, f: TickFormatter) -> Result<&'a pyo3::types::PyAny> {
+        let py = self.axis.py();
+        let f = Py::new(py, f)?;
+        Ok(py
+            .import("matplotlib.ticker")?
+            .call_method1("FuncFormatter", (f,))?)
+    }
+
+    /// Format the major tick labels with `f(value, pos)`,
+    /// where `pos` is the index of the tick if known.
+    ///
+    /// The closure is kept alive by the axis. A panic in the closure is raised as
+    /// `RuntimeError` in the call that renders the ticks, e.g. [crate::Figure::savefig],
+    /// which then returns [crate::Error::Python].
+    pub fn set_formatter<F>(&self, f: F) -> Result<&Self>
+    where
+        F: Fn(f64, Option<usize>) -> String + 'static,
+    {
+        let formatter = self.func_formatter(TickFormatter::new(move |v, pos| Ok(f(v, pos))))?;
+        self.axis
+            .call_method1("set_major_formatter", (formatter,))?;
+        Ok(self)
+    }
+
+    /// Like [Axis::set_formatter] for a closure that may fail,
+    /// errors are raised as `ValueError` in the call that renders the ticks.
+    pub fn try_set_formatter<F, E>(&self, f: F) -> Result<&Self>
+    where
+        F: Fn(f64, Option<usize>) -> Result<String, E> + 'static,
+        E: std::fmt::Display,
+    {
+        let formatter = self.func_formatter(TickFormatter::new(move |v, pos| {
+            f(v, pos).map_err(|e| e.to_string())
+        }))?;
+        self.axis
+            .call_method1("set_major_formatter", (formatter,))?;
+        Ok(self)
+    }
+
+    /// Format the minor tick labels with `f(value, pos)`, see [Axis::set_formatter].
+    pub fn set_minor_formatter<F>(&self, f: F) -> Result<&Self>
+    where
+        F: Fn(f64, Option<usize>) -> String + 'static,
+    {
+        let formatter = self.func_formatter(TickFormatter::new(move |v, pos| Ok(f(v, pos))))?;
+        self.axis
+            .call_method1("set_minor_formatter", (formatter,))?;
+        Ok(self)
+    }
+}
+
+impl<'a> Axes<'a> {
+    pub fn xaxis(&self) -> Result<Axis<'a>> {
+        let axis = self.axes.getattr("xaxis")?;
+        Ok(Axis { axis })
+    }
+
+    pub fn yaxis(&self) -> Result<Axis<'a>> {
+        let axis = self.axes.getattr("yaxis")?;
+        Ok(Axis { axis })
+    }
+}
@@ -0,0 +1,61 @@
+use crate::callback::ElementwiseFn;
+use crate::{Axes, Result};
+use pyo3::Py;
+
+impl<'a> Axes<'a> {
+    /// Create axes sharing the x axis, with an independent y axis on the right.
+    /// See `matplotlib.axes.Axes.twinx` for more details.
+    pub fn twinx(&self) -> Result<Axes<'a>> {
+        let axes = self.axes.call_method0("twinx")?;
+        Ok(Axes { py: self.py, axes })
+    }
+
+    /// Create axes sharing the y axis, with an independent x axis on the top.
+    /// See `matplotlib.axes.Axes.twiny` for more details.
+    pub fn twiny(&self) -> Result<Axes<'a>> {
+        let axes = self.axes.call_method0("twiny")?;
+        Ok(Axes { py: self.py, axes })
+    }
+
+    fn secondary_axis<F, G>(
+        &self,
+        method: &str,
+        location: f64,
+        forward: F,
+        inverse: G,
+    ) -> Result<Axes<'a>>
+    where
+        F: Fn(f64) -> f64 + 'static,
+        G: Fn(f64) -> f64 + 'static,
+    {
+        let forward = Py::new(self.py, ElementwiseFn::new(forward))?;
+        let inverse = Py::new(self.py, ElementwiseFn::new(inverse))?;
+        let axes = self
+            .axes
+            .call_method1(method, (location, (forward, inverse)))?;
+        Ok(Axes { py: self.py, axes })
+    }
+
+    /// Add a second x axis at `location` in axes coordinates (`1.` is the top edge),
+    /// showing the data converted by `forward`, with `inverse` converting back.
+    ///
+    /// E.g. frequency in Hz to period in seconds: `ax.secondary_xaxis(1., |f| 1. / f, |t| 1. / t)`.
+    /// See `matplotlib.axes.Axes.secondary_xaxis` for more details.
+    pub fn secondary_xaxis<F, G>(&self, location: f64, forward: F, inverse: G) -> Result<Axes<'a>>
+    where
+        F: Fn(f64) -> f64 + 'static,
+        G: Fn(f64) -> f64 + 'static,
+    {
+        self.secondary_axis("secondary_xaxis", location, forward, inverse)
+    }
+
+    /// Add a second y axis at `location` in axes coordinates (`1.` is the right edge),
+    /// see [Axes::secondary_xaxis].
+    pub fn secondary_yaxis<F, G>(&self, location: f64, forward: F, inverse: G) -> Result<Axes<'a>>
+    where
+        F: Fn(f64) -> f64 + 'static,
+        G: Fn(f64) -> f64 + 'static,
+    {
+        self.secondary_axis("secondary_yaxis", location, forward, inverse)
+    }
+}